@@ -0,0 +1,49 @@
+use fast_log::log::RuntimeType;
+
+use rbatis::rbatis::Rbatis;
+use rbatis_core::Error;
+
+use crate::BizActivity;
+
+///py sql is checked at compile time,the SELECT call RB.py_fetch()
+#[py_sql(crate::RB, "
+    SELECT * FROM biz_activity WHERE delete_flag = 0
+    if name != null:
+      AND name like #{name}")]
+async fn py_select(name: &str) -> Result<Vec<BizActivity>, Error> {}
+
+///the rb and tx_id args are not the sql args,UPDATE call rb.py_exec()
+#[py_sql(rb, "UPDATE biz_activity SET status = #{status} WHERE id = #{id}")]
+async fn py_update_status(rb: &Rbatis, tx_id: &str, id: &str, status: i32) -> Result<u64, Error> {}
+
+///raw sql,#{id} is the prepare arg
+#[sql(crate::RB, "select * from biz_activity where id = #{id}")]
+async fn find(id: &str) -> Result<BizActivity, Error>;
+
+///the expr start with an arg,${} is joined into the sql
+#[sql(rb, "update ${table} set name = #{arg.name} where id = #{arg.id}")]
+async fn update_name(rb: &Rbatis, tx_id: &str, table: &str, arg: &BizActivity) -> Result<u64, Error>;
+
+#[test]
+pub fn test_py_sql() {
+    async_std::task::block_on(async {
+        fast_log::log::init_log("requests.log", &RuntimeType::Std);
+        crate::RB.link(crate::MYSQL_URL).await.unwrap();
+        let r = py_select("%test%").await;
+        println!("{:?}", r);
+        let r = py_update_status(&crate::RB, "", "1", 1).await;
+        println!("{:?}", r);
+    });
+}
+
+#[test]
+pub fn test_sql() {
+    async_std::task::block_on(async {
+        fast_log::log::init_log("requests.log", &RuntimeType::Std);
+        crate::RB.link(crate::MYSQL_URL).await.unwrap();
+        let r = find("1").await.unwrap();
+        println!("{:?}", r);
+        let r = update_name(&crate::RB, "", "biz_activity", &r).await;
+        println!("{:?}", r);
+    });
+}
@@ -1,4 +1,5 @@
 use crate::db::DriverType;
+use crate::dialect::get_dialect;
 
 ///the stmt replace str convert
 pub trait StmtConvert {
@@ -7,6 +8,9 @@ pub trait StmtConvert {
 
 impl StmtConvert for DriverType {
     fn stmt_convert(&self, index: usize) -> String {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.stmt_convert(index);
+        }
         match &self {
             DriverType::Postgres => {
                 format!(" ${} ", index + 1)
@@ -17,6 +21,15 @@ impl StmtConvert for DriverType {
             DriverType::Sqlite => {
                 " ? ".to_string()
             }
+            DriverType::Mssql => {
+                format!(" @p{} ", index + 1)
+            }
+            DriverType::Oracle => {
+                format!(" :{} ", index + 1)
+            }
+            DriverType::ClickHouse => {
+                " ? ".to_string()
+            }
             DriverType::None => {
                 panic!("[rbatis] un support none for driver type!")
             }
@@ -75,6 +75,16 @@ where
 
     /// fetch into an json array
     fn fetch_json(&mut self) -> BoxFuture<Result<Vec<serde_json::Value>, crate::Error>>;
+
+    /// fetch the next row into json,None if no more rows
+    fn next_json(&mut self) -> BoxFuture<Result<Option<serde_json::Value>, crate::Error>>;
+
+    /// fetch into an json array,every row is an array of the column values(in column order),for decode tuples
+    fn fetch_json_values(&mut self) -> BoxFuture<Result<Vec<serde_json::Value>, crate::Error>>;
+
+    /// fetch into json arrays,one array for each result set
+    /// (stored procedures or ';' separated selects return several result sets)
+    fn fetch_json_sets(&mut self) -> BoxFuture<Result<Vec<Vec<serde_json::Value>>, crate::Error>>;
 }
 
 // Prevent users from implementing the `Row` trait.
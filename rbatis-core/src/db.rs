@@ -1,20 +1,36 @@
 use std::time::Duration;
 
+use async_stream::try_stream;
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use serde::de::DeserializeOwned;
 
+use crate::clickhouse::{ClickHouseClient, ClickHouseCursor, ClickHouseQuery};
+use crate::connection::Connection;
 use crate::cursor::Cursor;
 use crate::Error;
 use crate::executor::Executor;
 use crate::mysql::{MySql, MySqlConnection, MySqlCursor, MySqlPool};
 use crate::pool::Builder;
 use crate::pool::PoolConnection;
-use crate::postgres::{PgConnection, PgCursor, PgPool, Postgres};
+use crate::pool::PoolState;
+use crate::postgres::{PgConnection, PgCursor, PgListener, PgPool, Postgres};
 use crate::query::{Query, query};
 use crate::runtime::Mutex;
 use crate::sqlite::{Sqlite, SqliteConnection, SqliteCursor, SqlitePool};
 use crate::transaction::Transaction;
 
+/// an notification of Postgres NOTIFY
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct DBNotification {
+    /// the process id of the notifying backend
+    pub process_id: u32,
+    pub channel: String,
+    /// empty payload is an empty string
+    pub payload: String,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PoolOptions {
     pub max_size: u32,
@@ -23,6 +39,9 @@ pub struct PoolOptions {
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub test_on_acquire: bool,
+    /// the first wait of reconnecting when the database is unreachable,every retry double it
+    pub reconnect_backoff: Duration,
+    pub reconnect_max_backoff: Duration,
 }
 
 impl Default for PoolOptions {
@@ -42,6 +61,9 @@ impl Default for PoolOptions {
             idle_timeout: None,
             // If true, test the health of a connection on acquire
             test_on_acquire: true,
+            // retry connecting after 100ms,200ms,400ms ... at most 5 seconds(until connect_timeout)
+            reconnect_backoff: Duration::from_millis(100),
+            reconnect_max_backoff: Duration::from_secs(5),
         }
     }
 }
@@ -53,12 +75,19 @@ impl PoolOptions {
 }
 
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum DriverType {
     None = 0,
     Mysql = 1,
     Postgres = 2,
     Sqlite = 3,
+    /// SQL Server(2012+) sql dialect('@p1' placeholders,TOP,OFFSET ... FETCH) to build sql,for example Wrapper::new(&DriverType::Mssql),
+    /// there is no SQL Server(TDS) driver,DBPool can not connect to it
+    Mssql = 4,
+    /// Oracle(12c+),only the sql dialect(':1' placeholders,OFFSET ... FETCH/ROWNUM paging),the pool can not connect now
+    Oracle = 5,
+    /// ClickHouse over the HTTP(s) interface,the args are sent as the query parameters,no transaction
+    ClickHouse = 6,
 }
 
 #[derive(Debug)]
@@ -67,6 +96,7 @@ pub struct DBPool {
     pub mysql: Option<MySqlPool>,
     pub postgres: Option<PgPool>,
     pub sqlite: Option<SqlitePool>,
+    pub clickhouse: Option<ClickHouseClient>,
 }
 
 
@@ -78,6 +108,7 @@ impl DBPool {
             mysql: None,
             postgres: None,
             sqlite: None,
+            clickhouse: None,
         };
         if driver.starts_with("mysql") {
             pool.driver_type = DriverType::Mysql;
@@ -88,6 +119,11 @@ impl DBPool {
         } else if driver.starts_with("sqlite") {
             pool.driver_type = DriverType::Sqlite;
             pool.sqlite = Some(SqlitePool::new(driver).await?);
+        } else if driver.starts_with("clickhouse") {
+            pool.driver_type = DriverType::ClickHouse;
+            pool.clickhouse = Some(ClickHouseClient::new(driver)?);
+        } else if driver.starts_with("oracle") {
+            return Err(Error::from("[rbatis] Oracle driver can not connect now,only the sql dialect of DriverType::Oracle is support!"));
         } else {
             return Err(Error::from("unsupport driver type!"));
         }
@@ -101,6 +137,7 @@ impl DBPool {
             mysql: None,
             postgres: None,
             sqlite: None,
+            clickhouse: None,
         };
         if driver.starts_with("mysql") {
             pool.driver_type = DriverType::Mysql;
@@ -110,7 +147,8 @@ impl DBPool {
                 .connect_timeout(opt.connect_timeout)
                 .min_size(opt.min_size)
                 .idle_timeout(opt.idle_timeout)
-                .test_on_acquire(opt.test_on_acquire);
+                .test_on_acquire(opt.test_on_acquire)
+                .reconnect_backoff(opt.reconnect_backoff, opt.reconnect_max_backoff);
             pool.mysql = Some(build.build(driver).await?);
         } else if driver.starts_with("postgres") {
             pool.driver_type = DriverType::Postgres;
@@ -120,7 +158,8 @@ impl DBPool {
                 .connect_timeout(opt.connect_timeout)
                 .min_size(opt.min_size)
                 .idle_timeout(opt.idle_timeout)
-                .test_on_acquire(opt.test_on_acquire);
+                .test_on_acquire(opt.test_on_acquire)
+                .reconnect_backoff(opt.reconnect_backoff, opt.reconnect_max_backoff);
             pool.postgres = Some(build.build(driver).await?);
         } else if driver.starts_with("sqlite") {
             pool.driver_type = DriverType::Sqlite;
@@ -130,8 +169,15 @@ impl DBPool {
                 .connect_timeout(opt.connect_timeout)
                 .min_size(opt.min_size)
                 .idle_timeout(opt.idle_timeout)
-                .test_on_acquire(opt.test_on_acquire);
+                .test_on_acquire(opt.test_on_acquire)
+                .reconnect_backoff(opt.reconnect_backoff, opt.reconnect_max_backoff);
             pool.sqlite = Some(build.build(driver).await?);
+        } else if driver.starts_with("clickhouse") {
+            //no pool,every statement is an HTTP request
+            pool.driver_type = DriverType::ClickHouse;
+            pool.clickhouse = Some(ClickHouseClient::new(driver)?);
+        } else if driver.starts_with("oracle") {
+            return Err(Error::from("[rbatis] Oracle driver can not connect now,only the sql dialect of DriverType::Oracle is support!"));
         } else {
             return Err(Error::from("unsupport driver type!"));
         }
@@ -140,9 +186,18 @@ impl DBPool {
 
     pub fn make_query<'f, 's>(&'f self, sql: &'s str) -> crate::Result<DBQuery<'s>> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                return Ok(DBQuery {
+                    driver_type: DriverType::ClickHouse,
+                    mysql: None,
+                    postgres: None,
+                    sqlite: None,
+                    clickhouse: Some(ClickHouseQuery::new(sql)),
+                });
+            }
             &DriverType::Mysql => {
                 let q: Query<MySql> = query(sql);
                 return Ok(DBQuery {
@@ -150,6 +205,7 @@ impl DBPool {
                     mysql: Some(q),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -159,6 +215,7 @@ impl DBPool {
                     mysql: None,
                     postgres: Some(q),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -168,6 +225,7 @@ impl DBPool {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(q),
+                    clickhouse: None,
                 });
             }
         }
@@ -177,9 +235,18 @@ impl DBPool {
     /// Waits for at most the configured connection timeout before returning an error.
     pub async fn acquire(&self) -> crate::Result<DBPoolConn> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                return Ok(DBPoolConn {
+                    driver_type: DriverType::ClickHouse,
+                    mysql: None,
+                    postgres: None,
+                    sqlite: None,
+                    clickhouse: self.clickhouse.clone(),
+                });
+            }
             &DriverType::Mysql => {
                 let conn = self.mysql.as_ref().unwrap().acquire().await?;
                 return Ok(DBPoolConn {
@@ -187,6 +254,7 @@ impl DBPool {
                     mysql: Some(conn),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -196,6 +264,7 @@ impl DBPool {
                     mysql: None,
                     postgres: Some(conn),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -205,16 +274,64 @@ impl DBPool {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(conn),
+                    clickhouse: None,
                 });
             }
         }
     }
 
+    /// the active/idle connections,pending acquires and acquire wait time histogram of the pool
+    pub fn state(&self) -> crate::Result<PoolState> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                return Err(Error::from("[rbatis] ClickHouse have no pool,every statement is an HTTP request!"));
+            }
+            &DriverType::Mysql => {
+                return Ok(self.mysql.as_ref().unwrap().state());
+            }
+            &DriverType::Postgres => {
+                return Ok(self.postgres.as_ref().unwrap().state());
+            }
+            &DriverType::Sqlite => {
+                return Ok(self.sqlite.as_ref().unwrap().state());
+            }
+        }
+    }
+
+    /// listen the channels(Postgres LISTEN) on an dedicated conn(not from this pool,reconnect and re-listen if the conn is lost),
+    /// return the stream of notifications
+    pub async fn listen(&self, channels: &[&str]) -> crate::Result<BoxStream<'static, crate::Result<DBNotification>>> {
+        match &self.driver_type {
+            &DriverType::Postgres => {
+                let mut listener = PgListener::new(self.postgres.as_ref().unwrap().url()).await?;
+                listener.listen_all(channels.iter().copied()).await?;
+                let mut stream = listener.into_stream();
+                Ok(Box::pin(try_stream! {
+                    while let Some(n) = stream.next().await {
+                        let n = n?;
+                        yield DBNotification {
+                            process_id: n.process_id(),
+                            channel: n.channel().to_string(),
+                            payload: n.payload().to_string(),
+                        };
+                    }
+                }))
+            }
+            _ => Err(Error::from(format!("[rbatis] listen only support Postgres,not support DriverType:{:?}", self.driver_type))),
+        }
+    }
+
     pub async fn begin(&self) -> crate::Result<DBTx> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                return Err(Error::from("[rbatis] ClickHouse not support transaction!"));
+            }
             &DriverType::Mysql => {
                 Ok(DBTx {
                     driver_type: DriverType::Mysql,
@@ -283,14 +400,18 @@ pub struct DBQuery<'q> {
     pub mysql: Option<Query<'q, MySql>>,
     pub postgres: Option<Query<'q, Postgres>>,
     pub sqlite: Option<Query<'q, Sqlite>>,
+    pub clickhouse: Option<ClickHouseQuery<'q>>,
 }
 
 impl<'q> DBQuery<'q> {
     pub fn bind_value(&mut self, t: &serde_json::Value) -> crate::Result<()> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                self.clickhouse.as_mut().unwrap().bind(t);
+            }
             &DriverType::Mysql => {
                 let mut q = self.mysql.take().unwrap();
                 match t {
@@ -374,15 +495,83 @@ pub struct DBPoolConn {
     pub mysql: Option<PoolConnection<MySqlConnection>>,
     pub postgres: Option<PoolConnection<PgConnection>>,
     pub sqlite: Option<PoolConnection<SqliteConnection>>,
+    pub clickhouse: Option<ClickHouseClient>,
 }
 
 
 impl DBPoolConn {
+    /// begin tx on this conn
+    pub async fn begin(self) -> crate::Result<DBTx> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                return Err(Error::from("[rbatis] ClickHouse not support transaction!"));
+            }
+            &DriverType::Mysql => {
+                Ok(DBTx {
+                    driver_type: DriverType::Mysql,
+                    mysql: Some(self.mysql.unwrap().begin().await?),
+                    postgres: None,
+                    sqlite: None,
+                })
+            }
+            &DriverType::Postgres => {
+                Ok(DBTx {
+                    driver_type: DriverType::Postgres,
+                    mysql: None,
+                    postgres: Some(self.postgres.unwrap().begin().await?),
+                    sqlite: None,
+                })
+            }
+            &DriverType::Sqlite => {
+                Ok(DBTx {
+                    driver_type: DriverType::Sqlite,
+                    mysql: None,
+                    postgres: None,
+                    sqlite: Some(Mutex::new(self.sqlite.unwrap().begin().await?)),
+                })
+            }
+        }
+    }
+
+    /// close this conn instead of return it to the pool(for example the session state of it can not be reset)
+    pub async fn close(self) -> crate::Result<()> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                return Ok(());
+            }
+            &DriverType::Mysql => {
+                self.mysql.unwrap().close().await
+            }
+            &DriverType::Postgres => {
+                self.postgres.unwrap().close().await
+            }
+            &DriverType::Sqlite => {
+                self.sqlite.unwrap().close().await
+            }
+        }
+    }
+
     pub fn fetch<'q>(&mut self, sql: &'q str) -> crate::Result<DBCursor<'_, 'q>> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let data = ClickHouseCursor::new(self.clickhouse.clone().unwrap(), sql.to_string(), vec![]);
+                return Ok(DBCursor {
+                    driver_type: DriverType::ClickHouse,
+                    mysql: None,
+                    postgres: None,
+                    sqlite: None,
+                    clickhouse: Some(data),
+                });
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().fetch(sql);
                 return Ok(DBCursor {
@@ -390,6 +579,7 @@ impl DBPoolConn {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -399,6 +589,7 @@ impl DBPoolConn {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -408,16 +599,49 @@ impl DBPoolConn {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 });
             }
         }
     }
 
+    /// Postgres only: run 'COPY ... FROM STDIN' and send the data chunks,return the copied rows
+    pub async fn copy_in<I>(&mut self, sql: &str, chunks: I) -> crate::Result<u64>
+        where I: IntoIterator<Item=crate::Result<Vec<u8>>> {
+        match &self.driver_type {
+            &DriverType::Postgres => {
+                let data = self.postgres.as_mut().unwrap().copy_in(sql, chunks).await?;
+                return Ok(data);
+            }
+            _ => {
+                return Err(Error::from(format!("[rbatis] copy_in only support Postgres,not support DriverType:{:?}", self.driver_type)));
+            }
+        }
+    }
+
+    /// MySQL only: run 'LOAD DATA LOCAL INFILE' and send the data chunks as the file content,return the loaded rows
+    pub async fn load_data_local<I>(&mut self, sql: &str, chunks: I) -> crate::Result<u64>
+        where I: IntoIterator<Item=crate::Result<Vec<u8>>> {
+        match &self.driver_type {
+            &DriverType::Mysql => {
+                let data = self.mysql.as_mut().unwrap().load_data_local(sql, chunks).await?;
+                return Ok(data);
+            }
+            _ => {
+                return Err(Error::from(format!("[rbatis] load_data_local only support Mysql,not support DriverType:{:?}", self.driver_type)));
+            }
+        }
+    }
+
     pub async fn execute(&mut self, sql: &str) -> crate::Result<u64> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_ref().unwrap().execute(sql, &[]).await?;
+                return Ok(data);
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().execute(sql).await?;
                 return Ok(data);
@@ -435,9 +659,20 @@ impl DBPoolConn {
 
     pub fn fetch_parperd<'q>(&mut self, sql: DBQuery<'q>) -> crate::Result<DBCursor<'_, 'q>> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let (sql, params) = sql.clickhouse.unwrap().to_query()?;
+                let data = ClickHouseCursor::new(self.clickhouse.clone().unwrap(), sql, params);
+                return Ok(DBCursor {
+                    driver_type: DriverType::ClickHouse,
+                    mysql: None,
+                    postgres: None,
+                    sqlite: None,
+                    clickhouse: Some(data),
+                });
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().fetch(sql.mysql.unwrap());
                 return Ok(DBCursor {
@@ -445,6 +680,7 @@ impl DBPoolConn {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -454,6 +690,7 @@ impl DBPoolConn {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -463,6 +700,7 @@ impl DBPoolConn {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 });
             }
         }
@@ -470,9 +708,14 @@ impl DBPoolConn {
 
     pub async fn execute_parperd(&mut self, sql: DBQuery<'_>) -> crate::Result<u64> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let (sql, params) = sql.clickhouse.unwrap().to_query()?;
+                let data = self.clickhouse.as_ref().unwrap().execute(&sql, &params).await?;
+                return Ok(data);
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().execute(sql.mysql.unwrap()).await?;
                 return Ok(data);
@@ -495,6 +738,7 @@ pub struct DBCursor<'c, 'q> {
     pub mysql: Option<MySqlCursor<'c, 'q>>,
     pub postgres: Option<PgCursor<'c, 'q>>,
     pub sqlite: Option<SqliteCursor<'c, 'q>>,
+    pub clickhouse: Option<ClickHouseCursor>,
 }
 
 impl<'c, 'q> DBCursor<'c, 'q> {
@@ -502,9 +746,13 @@ impl<'c, 'q> DBCursor<'c, 'q> {
     pub async fn decode_json<T>(&mut self) -> Result<T, crate::Error>
         where T: DeserializeOwned {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_mut().unwrap().decode_json().await?;
+                return Ok(data);
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().decode_json().await?;
                 return Ok(data);
@@ -523,9 +771,13 @@ impl<'c, 'q> DBCursor<'c, 'q> {
     /// only fetch json
     pub async fn fetch_json(&mut self) -> Result<Vec<serde_json::Value>, crate::Error> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
                 return Err(Error::from("un init DBPool!"));
             }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_mut().unwrap().fetch_json().await?;
+                return Ok(data);
+            }
             &DriverType::Mysql => {
                 let data = self.mysql.as_mut().unwrap().fetch_json().await?;
                 return Ok(data);
@@ -540,6 +792,81 @@ impl<'c, 'q> DBCursor<'c, 'q> {
             }
         }
     }
+
+    /// fetch the next row into json,None if no more rows
+    pub async fn next_json(&mut self) -> Result<Option<serde_json::Value>, crate::Error> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_mut().unwrap().next_json().await?;
+                return Ok(data);
+            }
+            &DriverType::Mysql => {
+                let data = self.mysql.as_mut().unwrap().next_json().await?;
+                return Ok(data);
+            }
+            &DriverType::Postgres => {
+                let data = self.postgres.as_mut().unwrap().next_json().await?;
+                return Ok(data);
+            }
+            &DriverType::Sqlite => {
+                let data = self.sqlite.as_mut().unwrap().next_json().await?;
+                return Ok(data);
+            }
+        }
+    }
+
+    /// fetch json,every row is an array of the column values(in column order)
+    pub async fn fetch_json_values(&mut self) -> Result<Vec<serde_json::Value>, crate::Error> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_mut().unwrap().fetch_json_values().await?;
+                return Ok(data);
+            }
+            &DriverType::Mysql => {
+                let data = self.mysql.as_mut().unwrap().fetch_json_values().await?;
+                return Ok(data);
+            }
+            &DriverType::Postgres => {
+                let data = self.postgres.as_mut().unwrap().fetch_json_values().await?;
+                return Ok(data);
+            }
+            &DriverType::Sqlite => {
+                let data = self.sqlite.as_mut().unwrap().fetch_json_values().await?;
+                return Ok(data);
+            }
+        }
+    }
+
+    /// fetch json arrays,one array for each result set
+    pub async fn fetch_json_sets(&mut self) -> Result<Vec<Vec<serde_json::Value>>, crate::Error> {
+        match &self.driver_type {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle => {
+                return Err(Error::from("un init DBPool!"));
+            }
+            &DriverType::ClickHouse => {
+                let data = self.clickhouse.as_mut().unwrap().fetch_json_sets().await?;
+                return Ok(data);
+            }
+            &DriverType::Mysql => {
+                let data = self.mysql.as_mut().unwrap().fetch_json_sets().await?;
+                return Ok(data);
+            }
+            &DriverType::Postgres => {
+                let data = self.postgres.as_mut().unwrap().fetch_json_sets().await?;
+                return Ok(data);
+            }
+            &DriverType::Sqlite => {
+                let data = self.sqlite.as_mut().unwrap().fetch_json_sets().await?;
+                return Ok(data);
+            }
+        }
+    }
 }
 
 
@@ -553,7 +880,7 @@ pub struct DBTx {
 impl DBTx {
     pub async fn commit(&mut self) -> crate::Result<DBPoolConn> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
@@ -563,6 +890,7 @@ impl DBTx {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 })
             }
             &DriverType::Postgres => {
@@ -572,6 +900,7 @@ impl DBTx {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 })
             }
             &DriverType::Sqlite => {
@@ -582,6 +911,7 @@ impl DBTx {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 })
             }
         }
@@ -589,7 +919,7 @@ impl DBTx {
 
     pub async fn rollback(&mut self) -> crate::Result<DBPoolConn> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
@@ -599,6 +929,7 @@ impl DBTx {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 })
             }
             &DriverType::Postgres => {
@@ -608,6 +939,7 @@ impl DBTx {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 })
             }
             &DriverType::Sqlite => {
@@ -617,6 +949,7 @@ impl DBTx {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 })
             }
         }
@@ -626,7 +959,7 @@ impl DBTx {
     ///TODO find better way reduce the same code
     pub fn fetch<'q>(&mut self, sql: &'q str) -> crate::Result<DBCursor<'_, 'q>> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
@@ -636,6 +969,7 @@ impl DBTx {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -645,6 +979,7 @@ impl DBTx {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -655,14 +990,43 @@ impl DBTx {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 });
             }
         }
     }
 
+    /// Postgres only: run 'COPY ... FROM STDIN' and send the data chunks,return the copied rows
+    pub async fn copy_in<I>(&mut self, sql: &str, chunks: I) -> crate::Result<u64>
+        where I: IntoIterator<Item=crate::Result<Vec<u8>>> {
+        match &self.driver_type {
+            &DriverType::Postgres => {
+                let data = self.postgres.as_mut().unwrap().copy_in(sql, chunks).await?;
+                return Ok(data);
+            }
+            _ => {
+                return Err(Error::from(format!("[rbatis] copy_in only support Postgres,not support DriverType:{:?}", self.driver_type)));
+            }
+        }
+    }
+
+    /// MySQL only: run 'LOAD DATA LOCAL INFILE' and send the data chunks as the file content,return the loaded rows
+    pub async fn load_data_local<I>(&mut self, sql: &str, chunks: I) -> crate::Result<u64>
+        where I: IntoIterator<Item=crate::Result<Vec<u8>>> {
+        match &self.driver_type {
+            &DriverType::Mysql => {
+                let data = self.mysql.as_mut().unwrap().load_data_local(sql, chunks).await?;
+                return Ok(data);
+            }
+            _ => {
+                return Err(Error::from(format!("[rbatis] load_data_local only support Mysql,not support DriverType:{:?}", self.driver_type)));
+            }
+        }
+    }
+
     pub async fn execute(&mut self, sql: &str) -> crate::Result<u64> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
@@ -682,7 +1046,7 @@ impl DBTx {
 
     pub fn fetch_parperd<'q>(&mut self, sql: DBQuery<'q>) -> crate::Result<DBCursor<'_, 'q>> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
@@ -692,6 +1056,7 @@ impl DBTx {
                     mysql: Some(data),
                     postgres: None,
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Postgres => {
@@ -701,6 +1066,7 @@ impl DBTx {
                     mysql: None,
                     postgres: Some(data),
                     sqlite: None,
+                    clickhouse: None,
                 });
             }
             &DriverType::Sqlite => {
@@ -711,6 +1077,7 @@ impl DBTx {
                     mysql: None,
                     postgres: None,
                     sqlite: Some(data),
+                    clickhouse: None,
                 });
             }
         }
@@ -718,7 +1085,7 @@ impl DBTx {
 
     pub async fn execute_parperd(&mut self, sql: DBQuery<'_>) -> crate::Result<u64> {
         match &self.driver_type {
-            &DriverType::None => {
+            &DriverType::None | &DriverType::Mssql | &DriverType::Oracle | &DriverType::ClickHouse => {
                 return Err(Error::from("un init DBPool!"));
             }
             &DriverType::Mysql => {
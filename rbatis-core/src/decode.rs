@@ -1,5 +1,8 @@
 //! Types and traits for decoding values from the database.
-use serde::de::DeserializeOwned;
+use std::fmt::{self, Display};
+
+use serde::de::{DeserializeOwned, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde_json::{Map, Value};
 
 use crate::database::Database;
 use crate::value::HasRawValue;
@@ -17,6 +20,7 @@ pub trait Decode<'de, DB>
 /// support decode types:
 /// serde_json::Value,BigDecimal, i8..i64,u8..u64,serde_json::Number,bool,String
 /// or object used serde_json macro object
+/// or tuple(the rows must be column value arrays,see is_tuple())
 pub fn json_decode<T: ?Sized>(datas: Vec<serde_json::Value>) -> Result<T, crate::Error>
     where T: DeserializeOwned {
     let mut js = serde_json::Value::Null;
@@ -92,6 +96,276 @@ pub fn json_decode<T: ?Sized>(datas: Vec<serde_json::Value>) -> Result<T, crate:
     }
 }
 
+/// decode an single value(the first column of the first row) into type,
+/// number is also decoded as String and bool(0/1),numeric String(for example decimal sum) as number
+pub fn json_decode_scalar<T>(value: Value) -> Result<T, crate::Error>
+    where T: DeserializeOwned {
+    let e = match serde_json::from_value(value.clone()) {
+        Ok(v) => return Ok(v),
+        Err(e) => e.to_string(),
+    };
+    let other = match &value {
+        Value::Number(n) => {
+            match std::any::type_name::<T>() {
+                "bool" | "core::option::Option<bool>" => Some(Value::Bool(n.as_f64().unwrap_or(0.0) != 0.0)),
+                _ => Some(Value::String(n.to_string())),
+            }
+        }
+        Value::String(s) => serde_json::from_str::<serde_json::Number>(s.trim()).ok().map(Value::Number),
+        _ => None,
+    };
+    if let Some(v) = other.and_then(|x| serde_json::from_value(x).ok()) {
+        return Ok(v);
+    }
+    Result::Err(decode_err!("[rbatis] json decode scalar: {}, fail:{}", std::any::type_name::<T>(), e))
+}
+
+/// one result set of an multi result sets call,each set can decode into different type
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResultSet {
+    pub rows: Vec<Value>,
+}
+
+impl ResultSet {
+    pub fn new(rows: Vec<Value>) -> Self {
+        Self { rows }
+    }
+
+    /// decode rows into type,for example: Vec<BizActivity>,i64
+    pub fn decode<T>(&self) -> Result<T, crate::Error>
+        where T: DeserializeOwned {
+        json_decode(self.rows.clone())
+    }
+
+    /// decode rows into type with DecodeOption
+    pub fn decode_opt<T>(&self, opt: &DecodeOption) -> Result<T, crate::Error>
+        where T: DeserializeOwned {
+        json_decode_opt(self.rows.clone(), opt)
+    }
+}
+
+/// typed multi result sets,implemented for tuples,every element decode one result set in order,for example:
+///   let (total, data): (i64, Vec<BizActivity>) = FromResultSets::from_result_sets(&sets, &DecodeOption::default())?;
+pub trait FromResultSets: Sized {
+    fn from_result_sets(sets: &[ResultSet], opt: &DecodeOption) -> Result<Self, crate::Error>;
+}
+
+macro_rules! impl_from_result_sets_for_tuple {
+    ($len:expr; $( ($idx:tt) -> $T:ident );+;) => {
+        impl<$($T,)+> FromResultSets for ($($T,)+)
+        where
+            $($T: DeserializeOwned,)+
+        {
+            fn from_result_sets(sets: &[ResultSet], opt: &DecodeOption) -> Result<Self, crate::Error> {
+                if sets.len() < $len {
+                    return Result::Err(decode_err!("[rbatis] expect {} result sets, but got {}!", $len, sets.len()));
+                }
+                Ok(($(sets[$idx].decode_opt::<$T>(opt)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_result_sets_for_tuple!(1;
+    (0) -> T1;
+);
+
+impl_from_result_sets_for_tuple!(2;
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_from_result_sets_for_tuple!(3;
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_from_result_sets_for_tuple!(4;
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_from_result_sets_for_tuple!(5;
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_from_result_sets_for_tuple!(6;
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+/// option of decode rows
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DecodeOption {
+    /// bridge column name to struct field name: exact -> snake_case -> case-insensitive.
+    /// for example legacy view column 'createTime' => field 'create_time'
+    pub bridge_column: bool,
+    /// strict mode,return an error listing the mismatches if an column can not map to struct field,
+    /// or an struct field is missing from the result columns(instead of silently defaulting to None)
+    pub strict: bool,
+}
+
+/// decode json vec to an object,with column name normalization of DecodeOption
+pub fn json_decode_opt<T: ?Sized>(mut datas: Vec<Value>, opt: &DecodeOption) -> Result<T, crate::Error>
+    where T: DeserializeOwned {
+    if opt.bridge_column || opt.strict {
+        if let Some(fields) = struct_fields::<T>() {
+            let mut unmapped: Vec<String> = vec![];
+            let mut missing: Vec<&str> = vec![];
+            for row in datas.iter_mut() {
+                if let Value::Object(m) = row {
+                    let old = std::mem::replace(m, Map::new());
+                    *m = bridge_columns(old, fields, opt.bridge_column, &mut unmapped);
+                    for field in fields.iter().copied() {
+                        if !m.contains_key(field) && !missing.contains(&field) {
+                            missing.push(field);
+                        }
+                    }
+                }
+            }
+            if opt.strict && (!unmapped.is_empty() || !missing.is_empty()) {
+                return Result::Err(decode_err!("[rbatis] strict decode: {} mismatch, unmapped columns: {:?}, missing fields: {:?}", std::any::type_name::<T>(), unmapped, missing));
+            }
+        }
+    }
+    json_decode(datas)
+}
+
+fn bridge_columns(row: Map<String, Value>, fields: &[&str], bridge: bool, unmapped: &mut Vec<String>) -> Map<String, Value> {
+    let mut new_row = Map::with_capacity(row.len());
+    let mut pending = vec![];
+    for (k, v) in row {
+        if fields.contains(&k.as_str()) {
+            new_row.insert(k, v);
+        } else {
+            pending.push((k, v));
+        }
+    }
+    for (k, v) in pending {
+        let field = if bridge { find_bridge_field(&k, fields) } else { None };
+        match field {
+            Some(field) if !new_row.contains_key(field) => {
+                new_row.insert(field.to_string(), v);
+            }
+            _ => {
+                if !unmapped.contains(&k) {
+                    unmapped.push(k.clone());
+                }
+                new_row.insert(k, v);
+            }
+        }
+    }
+    new_row
+}
+
+/// snake_case -> case-insensitive(ignore '_')
+fn find_bridge_field<'f>(column: &str, fields: &[&'f str]) -> Option<&'f str> {
+    let snake = to_snake_name(column);
+    if let Some(f) = fields.iter().find(|f| snake.eq(*f)) {
+        return Some(f);
+    }
+    let column = column.replace("_", "");
+    fields.iter().find(|f| f.replace("_", "").eq_ignore_ascii_case(&column)).map(|f| *f)
+}
+
+fn to_snake_name(name: &str) -> String {
+    let mut new_name = String::with_capacity(name.len() + 4);
+    for (index, x) in name.chars().enumerate() {
+        if x.is_uppercase() {
+            if index != 0 {
+                new_name.push('_');
+            }
+            new_name.push_str(x.to_lowercase().to_string().as_str());
+        } else {
+            new_name.push(x);
+        }
+    }
+    new_name
+}
+
+/// return field names of the struct(also Vec<struct>,Option<struct>) from serde,None if T is not an struct
+pub fn struct_fields<T: ?Sized>() -> Option<&'static [&'static str]>
+    where T: DeserializeOwned {
+    let mut fields = None;
+    let _ = T::deserialize(FieldsCapture { fields: &mut fields });
+    fields
+}
+
+#[derive(Debug)]
+struct CaptureEnd;
+
+impl Display for CaptureEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("capture end")
+    }
+}
+
+impl std::error::Error for CaptureEnd {}
+
+impl serde::de::Error for CaptureEnd {
+    fn custom<T: Display>(_: T) -> Self {
+        CaptureEnd
+    }
+}
+
+/// an Deserializer only capture the fields of deserialize_struct
+struct FieldsCapture<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de, 'a> Deserializer<'de> for FieldsCapture<'a> {
+    type Error = CaptureEnd;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(CaptureEnd)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(CaptureSeq { fields: self.fields })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _: &'static str, fields: &'static [&'static str], _: V) -> Result<V::Value, Self::Error> {
+        *self.fields = Some(fields);
+        Err(CaptureEnd)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf
+        unit unit_struct tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct CaptureSeq<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for CaptureSeq<'a> {
+    type Error = CaptureEnd;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error> {
+        seed.deserialize(FieldsCapture { fields: &mut *self.fields }).map(Some)
+    }
+}
+
 fn is_array(type_name: &str) -> bool {
     if type_name.starts_with("alloc::collections::linked_list")
         || type_name.starts_with("alloc::vec::Vec<")
@@ -107,3 +381,108 @@ fn is_array(type_name: &str) -> bool {
     }
     return false;
 }
+
+/// the type is an tuple(or Vec/Option of tuple),the rows must be fetched as column value arrays(in column order)
+pub fn is_tuple<T: ?Sized>() -> bool {
+    let mut type_name = std::any::type_name::<T>();
+    for prefix in &["core::option::Option<", "alloc::vec::Vec<", "alloc::collections::linked_list::LinkedList<"] {
+        if type_name.starts_with(prefix) {
+            type_name = &type_name[prefix.len()..];
+        }
+    }
+    type_name.starts_with('(') && !type_name.starts_with("()")
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    struct Activity {
+        id: Option<String>,
+        create_time: Option<String>,
+        pc_link: Option<String>,
+    }
+
+    #[test]
+    fn test_struct_fields() {
+        assert_eq!(struct_fields::<Activity>(), Some(&["id", "create_time", "pc_link"][..]));
+        assert_eq!(struct_fields::<Vec<Option<Activity>>>(), Some(&["id", "create_time", "pc_link"][..]));
+        assert_eq!(struct_fields::<i32>(), None);
+        assert_eq!(struct_fields::<Value>(), None);
+    }
+
+    #[test]
+    fn test_decode_tuple() {
+        assert!(is_tuple::<(String, i64)>());
+        assert!(is_tuple::<Vec<(String, i64)>>());
+        assert!(is_tuple::<Option<Vec<(String, i64)>>>());
+        assert!(!is_tuple::<Vec<String>>());
+        assert!(!is_tuple::<()>());
+        let rows = vec![serde_json::json!(["a", 1]), serde_json::json!(["b", 2])];
+        let r: Vec<(String, i64)> = json_decode(rows.clone()).unwrap();
+        assert_eq!(r, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+        let r: (String, i64) = json_decode(vec![rows[0].clone()]).unwrap();
+        assert_eq!(r, ("a".to_string(), 1));
+        let r: Option<(String, i64)> = json_decode(vec![]).unwrap();
+        assert_eq!(r, None);
+    }
+
+    #[test]
+    fn test_decode_scalar() {
+        let r: i64 = json_decode_scalar(serde_json::json!(3)).unwrap();
+        assert_eq!(r, 3);
+        let r: bool = json_decode_scalar(serde_json::json!(1)).unwrap();
+        assert!(r);
+        let r: String = json_decode_scalar(serde_json::json!(3)).unwrap();
+        assert_eq!(r, "3");
+        let r: f64 = json_decode_scalar(serde_json::json!("12.50")).unwrap();
+        assert_eq!(r, 12.5);
+        let r: Option<i64> = json_decode_scalar(Value::Null).unwrap();
+        assert_eq!(r, None);
+        assert!(json_decode_scalar::<i64>(Value::Null).is_err());
+    }
+
+    #[test]
+    fn test_from_result_sets() {
+        let sets = vec![
+            ResultSet::new(vec![serde_json::json!({"total": 1})]),
+            ResultSet::new(vec![serde_json::json!({"id": "1", "create_time": null, "pc_link": null})]),
+        ];
+        let (total, data): (i64, Vec<Activity>) = FromResultSets::from_result_sets(&sets, &DecodeOption::default()).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(data[0].id, Some("1".to_string()));
+        let r: Result<(i64, Vec<Activity>, Vec<Activity>), _> = FromResultSets::from_result_sets(&sets, &DecodeOption::default());
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_bridge_column() {
+        let rows = vec![serde_json::json!({"id": "1", "createTime": "2020-01-01", "PCLINK": "a"})];
+        let opt = DecodeOption { bridge_column: true, strict: false };
+        let r: Activity = json_decode_opt(rows.clone(), &opt).unwrap();
+        assert_eq!(r.create_time, Some("2020-01-01".to_string()));
+        assert_eq!(r.pc_link, Some("a".to_string()));
+
+        let r: Activity = json_decode_opt(rows.clone(), &DecodeOption::default()).unwrap();
+        assert_eq!(r.create_time, None);
+
+        let opt = DecodeOption { bridge_column: false, strict: true };
+        assert!(json_decode_opt::<Activity>(rows, &opt).is_err());
+    }
+
+    #[test]
+    fn test_strict_decode() {
+        let opt = DecodeOption { bridge_column: true, strict: true };
+        let rows = vec![serde_json::json!({"id": "1", "createTime": "2020-01-01", "pc_link": "a"})];
+        assert!(json_decode_opt::<Activity>(rows, &opt).is_ok());
+
+        let rows = vec![serde_json::json!({"id": "1", "remark": "a"})];
+        let e = json_decode_opt::<Vec<Activity>>(rows, &opt).err().unwrap().to_string();
+        assert!(e.contains("remark"));
+        assert!(e.contains("create_time"));
+        assert!(e.contains("pc_link"));
+    }
+}
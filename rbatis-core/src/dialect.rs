@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use serde_json::Value;
+
+use crate::db::DriverType;
+
+/// an user defined sql dialect,registered by set_dialect() it replace the builtin sql of the driver type,
+/// so an database speak the wire protocol of an driver type(or DriverType::None) can have it's own sql.
+/// the default methods are ANSI sql,call the builtin DriverType methods in the impl to keep an part of the builtin sql
+pub trait Dialect: Send + Sync {
+    /// the placeholder of the arg at index(start from 0),for example " ? " or " $1 "
+    fn stmt_convert(&self, index: usize) -> String;
+
+    /// return  (sql,value) of an date arg
+    fn date_convert(&self, value: &Value, index: usize) -> crate::Result<(String, Value)> {
+        Ok((self.stmt_convert(index), value.clone()))
+    }
+
+    /// return  sql of an page,for example: " LIMIT 10 OFFSET 20"
+    fn page_limit_sql(&self, offset: u64, size: u64) -> crate::Result<String> {
+        Ok(format!(" LIMIT {} OFFSET {}", size, offset))
+    }
+
+    /// return  sql of optional limit and offset
+    fn limit_offset_sql(&self, limit: Option<u64>, offset: Option<u64>) -> crate::Result<String> {
+        let mut sql = String::new();
+        if let Some(limit) = limit {
+            sql.push_str(format!(" LIMIT {}", limit).as_str());
+        }
+        if let Some(offset) = offset {
+            sql.push_str(format!(" OFFSET {}", offset).as_str());
+        }
+        Ok(sql)
+    }
+
+    /// return  the select sql with optional limit and offset
+    fn limit_select_sql(&self, sql: &str, limit: Option<u64>, offset: Option<u64>) -> crate::Result<String> {
+        Ok(format!("{}{}", sql, self.limit_offset_sql(limit, offset)?))
+    }
+
+    /// quote an table/column name,for example: "name"
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    /// shift the placeholders of an sql merged after offset args(Wrapper::merge,sub query),for example " $1 " to " $3 " by offset 2.
+    /// the default not change the sql('?' placeholders),the numbered dialect shift them by shift_numbered_placeholders()
+    fn shift_placeholders(&self, sql: &str, _offset: usize) -> String {
+        sql.to_string()
+    }
+}
+
+/// shift the numbered placeholders(prefix + number,out of quote) by offset,for example prefix "$": "a = $1" to "a = $3" by offset 2
+pub fn shift_numbered_placeholders(sql: &str, prefix: &str, offset: usize) -> String {
+    if offset == 0 || prefix.is_empty() {
+        return sql.to_string();
+    }
+    let mut result = String::with_capacity(sql.len());
+    let mut in_quote = false;
+    let mut rest = sql;
+    while let Some(c) = rest.chars().next() {
+        if c == '\'' {
+            in_quote = !in_quote;
+        }
+        if !in_quote && rest.starts_with(prefix) {
+            let digits = rest[prefix.len()..].chars().take_while(|x| x.is_ascii_digit()).count();
+            if digits > 0 {
+                let num = rest[prefix.len()..prefix.len() + digits].parse::<usize>().unwrap_or(0);
+                result.push_str(format!("{}{}", prefix, num + offset).as_str());
+                rest = &rest[prefix.len() + digits..];
+                continue;
+            }
+        }
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+lazy_static! {
+    static ref DIALECTS: RwLock<HashMap<DriverType, Arc<dyn Dialect>>> = RwLock::new(HashMap::new());
+}
+
+/// register the dialect of the driver type(replace the old)
+pub fn set_dialect(driver_type: DriverType, dialect: Arc<dyn Dialect>) {
+    DIALECTS.write().unwrap().insert(driver_type, dialect);
+}
+
+/// remove the dialect of the driver type,use the builtin sql again
+pub fn remove_dialect(driver_type: &DriverType) -> Option<Arc<dyn Dialect>> {
+    DIALECTS.write().unwrap().remove(driver_type)
+}
+
+/// the registered dialect of the driver type
+pub fn get_dialect(driver_type: &DriverType) -> Option<Arc<dyn Dialect>> {
+    let dialects = DIALECTS.read().unwrap();
+    if dialects.is_empty() {
+        return None;
+    }
+    dialects.get(driver_type).cloned()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::convert::StmtConvert;
+    use crate::db::DriverType;
+    use crate::dialect::{get_dialect, remove_dialect, set_dialect, shift_numbered_placeholders, Dialect};
+
+    struct ColonDialect {}
+
+    impl Dialect for ColonDialect {
+        fn stmt_convert(&self, index: usize) -> String {
+            format!(" :p{} ", index)
+        }
+    }
+
+    #[test]
+    fn test_dialect() {
+        set_dialect(DriverType::None, Arc::new(ColonDialect {}));
+        assert_eq!(DriverType::None.stmt_convert(1), " :p1 ");
+        let dialect = get_dialect(&DriverType::None).unwrap();
+        assert_eq!(dialect.limit_select_sql("SELECT * FROM biz_activity", Some(10), Some(20)).unwrap(), "SELECT * FROM biz_activity LIMIT 10 OFFSET 20");
+        assert_eq!(dialect.quote_identifier("name"), "\"name\"");
+        assert!(remove_dialect(&DriverType::None).is_some());
+        assert!(get_dialect(&DriverType::None).is_none());
+    }
+
+    #[test]
+    fn test_shift_numbered_placeholders() {
+        assert_eq!(shift_numbered_placeholders("a = $1 AND b = '$1' AND c = $12", "$", 2), "a = $3 AND b = '$1' AND c = $14");
+        assert_eq!(shift_numbered_placeholders("a = :p1", ":p", 0), "a = :p1");
+    }
+}
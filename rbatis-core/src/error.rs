@@ -76,6 +76,9 @@ pub enum Error {
 
     /// An error occurred decoding data received from the database.
     Decode(Box<dyn StdError + Send + Sync>),
+
+    /// An optimistic lock(version) update affected zero rows,the row is updated by others or not exist.
+    VersionConflict(String),
 }
 
 impl Error {
@@ -158,6 +161,8 @@ impl Display for Error {
             Error::PoolClosed => f.write_str("attempted to acquire a connection on a closed pool"),
 
             Error::Tls(ref err) => write!(f, "error during TLS upgrade: {}", err),
+
+            Error::VersionConflict(ref err) => write!(f, "version conflict: {}", err),
         }
     }
 }
@@ -227,6 +232,57 @@ impl From<std::string::String> for Error {
 }
 
 
+/// kind of constraint violation
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    /// duplicate key(unique index or primary key)
+    Unique,
+    /// null value for an not null column
+    NotNull,
+    /// foreign key
+    ForeignKey,
+    /// check constraint
+    Check,
+}
+
+/// structured constraint violation parsed from the driver error,
+/// so api can return 409/422 without exposing raw driver message
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintViolation {
+    pub kind: ConstraintKind,
+    /// constraint(or key/index) name,if the driver report it
+    pub constraint: Option<String>,
+    pub table: Option<String>,
+    /// the offending column,if it can be found
+    pub column: Option<String>,
+    /// the raw driver message
+    pub message: String,
+}
+
+impl Error {
+    pub fn is_version_conflict(&self) -> bool {
+        match self {
+            Error::VersionConflict(_) => true,
+            _ => false,
+        }
+    }
+
+    /// return the constraint violation if this is an database constraint error
+    pub fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        match self {
+            Error::Database(e) => e.constraint_violation(),
+            _ => None,
+        }
+    }
+}
+
+/// find the text between start and end,for example: find_between("Key (a)=(1)", "Key (", ")") == Some("a")
+pub(crate) fn find_between<'a>(arg: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let begin = arg.find(start)? + start.len();
+    let len = arg[begin..].find(end)?;
+    Some(&arg[begin..begin + len])
+}
+
 /// An error that was returned by the database.
 pub trait DatabaseError: StdError + Send + Sync + 'static {
     /// The primary, human-readable error message.
@@ -257,6 +313,11 @@ pub trait DatabaseError: StdError + Send + Sync + 'static {
         None
     }
 
+    /// parse duplicate-key / not-null / foreign-key / check violation,None if not an constraint violation
+    fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        None
+    }
+
     #[doc(hidden)]
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static);
 
@@ -406,54 +467,149 @@ impl Display for UnexpectedNullError {
 impl StdError for UnexpectedNullError {}
 
 
-impl Clone for Error {
-    fn clone(&self) -> Self {
-        Error::from(self.to_string())
+/// the owned copy of an DatabaseError(message,code and the constraint violation),
+/// used by Error::clone() and the serde of Error::Database
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseErrorSnapshot {
+    pub message: String,
+    pub code: Option<String>,
+    pub details: Option<String>,
+    pub hint: Option<String>,
+    pub table_name: Option<String>,
+    pub column_name: Option<String>,
+    pub constraint_name: Option<String>,
+    pub constraint_violation: Option<ConstraintViolation>,
+}
+
+impl DatabaseErrorSnapshot {
+    pub fn new(e: &dyn DatabaseError) -> Self {
+        Self {
+            message: e.message().to_string(),
+            code: e.code().map(|x| x.to_string()),
+            details: e.details().map(|x| x.to_string()),
+            hint: e.hint().map(|x| x.to_string()),
+            table_name: e.table_name().map(|x| x.to_string()),
+            column_name: e.column_name().map(|x| x.to_string()),
+            constraint_name: e.constraint_name().map(|x| x.to_string()),
+            constraint_violation: e.constraint_violation(),
+        }
     }
+}
 
-    fn clone_from(&mut self, source: &Self) {
-        *self = Self::from(source.to_string());
+impl Display for DatabaseErrorSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
     }
 }
 
+impl StdError for DatabaseErrorSnapshot {}
 
-use serde::ser::{Serialize, Serializer};
-use serde::{Deserialize, Deserializer};
-use serde::de::{Visitor};
+impl DatabaseError for DatabaseErrorSnapshot {
+    fn message(&self) -> &str {
+        &self.message
+    }
 
-// This is what #[derive(Serialize)] would generate.
-impl Serialize for Error {
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-    {
-        serializer.serialize_str(self.to_string().as_str())
+    fn code(&self) -> Option<&str> {
+        self.code.as_deref()
     }
-}
 
+    fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
 
-struct ErrorVisitor;
-impl<'de> Visitor<'de> for ErrorVisitor {
-    type Value = String;
+    fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
 
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a string")
+    fn table_name(&self) -> Option<&str> {
+        self.table_name.as_deref()
     }
 
-    fn visit_string<E>(self, v: String) -> std::result::Result<Self::Value, E>
-        where
-            E: std::error::Error,
-    {
-        Ok(v)
+    fn column_name(&self) -> Option<&str> {
+        self.column_name.as_deref()
+    }
+
+    fn constraint_name(&self) -> Option<&str> {
+        self.constraint_name.as_deref()
+    }
+
+    fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        self.constraint_violation.clone()
+    }
+
+    fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_mut_err(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+        self
     }
 
-    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    fn into_box_err(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+        self
+    }
+}
+
+/// the variants without an owned source(Io,Tls,Decode...) clone into the same variant with the message
+impl Clone for Error {
+    fn clone(&self) -> Self {
+        match self {
+            Error::E(e) => Error::E(e.clone()),
+            Error::Io(e) => Error::Io(io::Error::new(e.kind(), e.to_string())),
+            Error::UrlParse(e) => Error::UrlParse(e.clone()),
+            Error::Database(e) => Error::Database(Box::new(DatabaseErrorSnapshot::new(e.as_ref()))),
+            Error::RowNotFound => Error::RowNotFound,
+            Error::ColumnNotFound(name) => Error::ColumnNotFound(name.clone()),
+            Error::ColumnIndexOutOfBounds { index, len } => Error::ColumnIndexOutOfBounds { index: *index, len: *len },
+            Error::Protocol(e) => Error::Protocol(e.clone()),
+            Error::PoolTimedOut(e) => Error::PoolTimedOut(e.as_ref().map(|x| x.to_string().into())),
+            Error::PoolClosed => Error::PoolClosed,
+            Error::Tls(e) => Error::Tls(e.to_string().into()),
+            Error::Decode(e) => Error::Decode(e.to_string().into()),
+            Error::VersionConflict(e) => Error::VersionConflict(e.clone()),
+        }
+    }
+}
+
+
+use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer};
+
+/// the serde form of the structured variants,the others serialize into the message string(and deserialize into Error::E)
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum ErrorRepr {
+    Database(DatabaseErrorSnapshot),
+    VersionConflict { message: String },
+    RowNotFound,
+    ColumnNotFound { name: String },
+    ColumnIndexOutOfBounds { index: usize, len: usize },
+    PoolClosed,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ErrorDe {
+    Message(String),
+    Repr(ErrorRepr),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
         where
-            E: std::error::Error,
+            S: Serializer,
     {
-        Ok(v.to_string())
+        let repr = match self {
+            Error::Database(e) => ErrorRepr::Database(DatabaseErrorSnapshot::new(e.as_ref())),
+            Error::VersionConflict(e) => ErrorRepr::VersionConflict { message: e.clone() },
+            Error::RowNotFound => ErrorRepr::RowNotFound,
+            Error::ColumnNotFound(name) => ErrorRepr::ColumnNotFound { name: name.to_string() },
+            Error::ColumnIndexOutOfBounds { index, len } => ErrorRepr::ColumnIndexOutOfBounds { index: *index, len: *len },
+            Error::PoolClosed => ErrorRepr::PoolClosed,
+            _ => return serializer.serialize_str(self.to_string().as_str()),
+        };
+        repr.serialize(serializer)
     }
-
 }
 
 impl<'de> Deserialize<'de> for Error {
@@ -461,8 +617,16 @@ impl<'de> Deserialize<'de> for Error {
         where
             D: Deserializer<'de>,
     {
-        let r = deserializer.deserialize_string(ErrorVisitor)?;
-        return Ok(Error::from(r));
+        let e = match ErrorDe::deserialize(deserializer)? {
+            ErrorDe::Message(e) => Error::from(e),
+            ErrorDe::Repr(ErrorRepr::Database(e)) => Error::Database(Box::new(e)),
+            ErrorDe::Repr(ErrorRepr::VersionConflict { message }) => Error::VersionConflict(message),
+            ErrorDe::Repr(ErrorRepr::RowNotFound) => Error::RowNotFound,
+            ErrorDe::Repr(ErrorRepr::ColumnNotFound { name }) => Error::ColumnNotFound(name.into_boxed_str()),
+            ErrorDe::Repr(ErrorRepr::ColumnIndexOutOfBounds { index, len }) => Error::ColumnIndexOutOfBounds { index, len },
+            ErrorDe::Repr(ErrorRepr::PoolClosed) => Error::PoolClosed,
+        };
+        Ok(e)
     }
 }
 
@@ -473,4 +637,44 @@ fn test_json_error(){
     let s= serde_json::to_string(&e).unwrap();
     println!("{}",s.as_str());
     let new_e:Error=serde_json::from_str(s.as_str()).unwrap();
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_find_between() {
+    assert_eq!(find_between("Key (email)=(a@b.c) already exists.", "Key (", ")="), Some("email"));
+    assert_eq!(find_between("Column 'name' cannot be null", "'", "'"), Some("name"));
+    assert_eq!(find_between("no quote", "'", "'"), None);
+}
+
+#[test]
+fn test_clone_and_json_keep_variant() {
+    let db = DatabaseErrorSnapshot {
+        message: "Duplicate entry '1' for key 'PRIMARY'".to_string(),
+        code: Some("23000".to_string()),
+        constraint_violation: Some(ConstraintViolation {
+            kind: ConstraintKind::Unique,
+            constraint: Some("PRIMARY".to_string()),
+            table: None,
+            column: None,
+            message: "Duplicate entry '1' for key 'PRIMARY'".to_string(),
+        }),
+        ..Default::default()
+    };
+    let e = Error::Database(Box::new(db.clone()));
+    let cloned = e.clone();
+    assert_eq!(cloned.constraint_violation(), db.constraint_violation);
+    let new_e: Error = serde_json::from_str(&serde_json::to_string(&e).unwrap()).unwrap();
+    assert_eq!(new_e.constraint_violation(), db.constraint_violation);
+    match new_e {
+        Error::Database(e) => assert_eq!(e.code(), Some("23000")),
+        _ => panic!("not Error::Database"),
+    }
+
+    let e = Error::VersionConflict("id = 1".to_string());
+    assert!(e.clone().is_version_conflict());
+    let new_e: Error = serde_json::from_str(&serde_json::to_string(&e).unwrap()).unwrap();
+    assert!(new_e.is_version_conflict());
+
+    let new_e: Error = serde_json::from_str("\"fail\"").unwrap();
+    assert_eq!(new_e.to_string(), "fail");
+}
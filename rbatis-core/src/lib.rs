@@ -13,6 +13,9 @@
 #[cfg(all(test, feature = "bench"))]
 extern crate test;
 
+#[macro_use]
+extern crate lazy_static;
+
 // HACK: Allow a feature name the same name as a dependency
 #[cfg(feature = "bigdecimal")]
 extern crate bigdecimal_ as bigdecimal;
@@ -37,6 +40,9 @@ pub mod executor;
 pub mod transaction;
 mod url;
 
+#[cfg(any(feature = "mysql", feature = "postgres"))]
+pub mod cache;
+
 #[macro_use]
 pub mod arguments;
 pub mod decode;
@@ -67,8 +73,12 @@ pub mod postgres;
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub mod sqlite;
 
+pub mod clickhouse;
+
 pub use error::{Error, Result};
 
 pub mod db;
 
-pub mod convert;
\ No newline at end of file
+pub mod convert;
+
+pub mod dialect;
\ No newline at end of file
@@ -8,9 +8,10 @@ use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
 use crate::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlRow, MySqlTypeInfo};
-use crate::mysql::protocol::{ColumnCount, ColumnDefinition, Row, Status};
+use crate::mysql::protocol::{ColumnCount, ColumnDefinition, LocalInfileData, Row, Status};
 use crate::pool::Pool;
 use crate::decode::json_decode;
+use crate::row::Row as _;
 
 pub struct MySqlCursor<'c, 'q> {
     source: ConnectionSource<'c, MySqlConnection>,
@@ -18,6 +19,8 @@ pub struct MySqlCursor<'c, 'q> {
     column_names: Arc<HashMap<Box<str>, u16>>,
     column_types: Vec<MySqlTypeInfo>,
     binary: bool,
+    //count of result sets,for multi result sets
+    result_sets: usize,
 }
 
 impl crate::cursor::private::Sealed for MySqlCursor<'_, '_> {}
@@ -36,6 +39,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             column_names: Arc::default(),
             column_types: Vec::new(),
             binary: true,
+            result_sets: 0,
             query: Some(query.into_parts()),
         }
     }
@@ -51,6 +55,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             column_names: Arc::default(),
             column_types: Vec::new(),
             binary: true,
+            result_sets: 0,
             query: Some(query.into_parts()),
         }
     }
@@ -72,21 +77,75 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
         Box::pin(async move {
             let mut arr = vec![];
             while let Some(row) = self.next().await? as Option<MySqlRow<'_>> {
-                let mut m = serde_json::Map::new();
-                let keys = row.names.keys();
-                for x in keys {
-                    let key = x.to_string();
-                    let key_str=key.as_str();
-                    let v:serde_json::Value = row.json_decode_impl(key_str)?;
-                    m.insert(key, v);
-                }
-                arr.push(serde_json::Value::Object(m));
+                arr.push(row_json(&row)?);
+            }
+            return Ok(arr);
+        })
+    }
+
+    fn next_json(&mut self) -> BoxFuture<Result<Option<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            match self.next().await? as Option<MySqlRow<'_>> {
+                Some(row) => Ok(Some(row_json(&row)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn fetch_json_values(&mut self) -> BoxFuture<Result<Vec<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            let mut arr = vec![];
+            while let Some(row) = self.next().await? as Option<MySqlRow<'_>> {
+                arr.push(row_values(&row)?);
             }
             return Ok(arr);
         })
     }
+
+    fn fetch_json_sets(&mut self) -> BoxFuture<Result<Vec<Vec<serde_json::Value>>, crate::Error>> {
+        Box::pin(async move {
+            let mut sets: Vec<Vec<serde_json::Value>> = vec![];
+            loop {
+                let item = match self.next().await? as Option<MySqlRow<'_>> {
+                    Some(row) => row_json(&row)?,
+                    None => break,
+                };
+                let index = self.result_sets.max(1) - 1;
+                while sets.len() <= index {
+                    sets.push(vec![]);
+                }
+                sets[index].push(item);
+            }
+            //result sets without rows
+            while sets.len() < self.result_sets {
+                sets.push(vec![]);
+            }
+            return Ok(sets);
+        })
+    }
 }
 
+fn row_json(row: &MySqlRow<'_>) -> crate::Result<serde_json::Value> {
+    let mut m = serde_json::Map::new();
+    let keys = row.names.keys();
+    for x in keys {
+        let key = x.to_string();
+        let key_str = key.as_str();
+        let v: serde_json::Value = row.json_decode_impl(key_str)?;
+        m.insert(key, v);
+    }
+    Ok(serde_json::Value::Object(m))
+}
+
+
+fn row_values(row: &MySqlRow<'_>) -> crate::Result<serde_json::Value> {
+    let mut arr = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let v: serde_json::Value = row.json_decode_impl(index)?;
+        arr.push(v);
+    }
+    Ok(serde_json::Value::Array(arr))
+}
 
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut MySqlCursor<'c, 'q>,
@@ -136,6 +195,15 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 return conn.stream.handle_err();
             }
 
+            // LOCAL INFILE request,only load_data_local() send the file content,
+            // send an empty file to end the statement and reject it
+            0xFB if initial => {
+                conn.stream.send(LocalInfileData(&[]), false).await?;
+                conn.affected_rows().await?;
+                conn.is_ready = true;
+                return Err(protocol_err!("'LOAD DATA LOCAL INFILE' can not run by fetch,use load_data_local()").into());
+            }
+
             _ if initial => {
                 // At the start of the results we expect to see a
                 // COLUMN_COUNT followed by N COLUMN_DEF
@@ -167,6 +235,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 }
 
                 cursor.column_names = Arc::new(column_names);
+                cursor.result_sets += 1;
                 initial = false;
             }
 
@@ -1,7 +1,7 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
-use crate::error::DatabaseError;
+use crate::error::{ConstraintKind, ConstraintViolation, DatabaseError, find_between};
 use crate::mysql::protocol::ErrPacket;
 
 #[derive(Debug)]
@@ -22,6 +22,38 @@ impl DatabaseError for MySqlError {
         self.0.sql_state.as_deref()
     }
 
+    fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        let message = self.message();
+        let (kind, constraint, table, column) = match self.0.error_code {
+            // Duplicate entry '1' for key 'name'
+            1062 | 1586 => {
+                let key = message.rfind(" for key ").and_then(|i| find_between(&message[i..], "'", "'"));
+                //mysql 8.0 key is 'table.key'
+                let key = key.map(|k| k.rsplit('.').next().unwrap_or(k));
+                (ConstraintKind::Unique, key, None, None)
+            }
+            // Column 'name' cannot be null / Field 'name' doesn't have a default value
+            1048 | 1364 => (ConstraintKind::NotNull, None, None, find_between(message, "'", "'")),
+            // Cannot add or update a child row: a foreign key constraint fails (`db`.`t`, CONSTRAINT `fk` FOREIGN KEY (`col`) REFERENCES ...)
+            1216 | 1217 | 1451 | 1452 => {
+                let table = find_between(message, "`.`", "`");
+                let constraint = find_between(message, "CONSTRAINT `", "`");
+                let column = find_between(message, "FOREIGN KEY (`", "`");
+                (ConstraintKind::ForeignKey, constraint, table, column)
+            }
+            // Check constraint 'name' is violated.
+            3819 => (ConstraintKind::Check, find_between(message, "'", "'"), None, None),
+            _ => return None,
+        };
+        Some(ConstraintViolation {
+            kind,
+            constraint: constraint.map(|x| x.to_string()),
+            table: table.map(|x| x.to_string()),
+            column: column.map(|x| x.to_string()),
+            message: message.to_string(),
+        })
+    }
+
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
@@ -61,3 +93,26 @@ fn test_error_downcasting() {
     assert_eq!(db_err.downcast_ref::<MySqlError>().0.error_code, 0xABCD);
     assert_eq!(db_err.downcast::<MySqlError>().0.error_code, 0xABCD);
 }
+
+#[test]
+fn test_constraint_violation() {
+    let error = MySqlError(ErrPacket {
+        error_code: 1062,
+        sql_state: Some("23000".into()),
+        error_message: "Duplicate entry 'a' for key 'biz_activity.name'".into(),
+    });
+    let v = error.constraint_violation().unwrap();
+    assert_eq!(v.kind, ConstraintKind::Unique);
+    assert_eq!(v.constraint, Some("name".to_string()));
+
+    let error = MySqlError(ErrPacket {
+        error_code: 1452,
+        sql_state: Some("23000".into()),
+        error_message: "Cannot add or update a child row: a foreign key constraint fails (`test`.`biz_order`, CONSTRAINT `fk_activity` FOREIGN KEY (`activity_id`) REFERENCES `biz_activity` (`id`))".into(),
+    });
+    let v = error.constraint_violation().unwrap();
+    assert_eq!(v.kind, ConstraintKind::ForeignKey);
+    assert_eq!(v.table, Some("biz_order".to_string()));
+    assert_eq!(v.constraint, Some("fk_activity".to_string()));
+    assert_eq!(v.column, Some("activity_id".to_string()));
+}
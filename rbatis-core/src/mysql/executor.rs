@@ -4,7 +4,7 @@ use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
 use crate::executor::{Execute, Executor, RefExecutor};
 use crate::mysql::protocol::{
-    self, ColumnDefinition, ComQuery, ComStmtExecute, ComStmtPrepare, ComStmtPrepareOk, FieldFlags,
+    self, ColumnDefinition, ComQuery, ComStmtClose, ComStmtExecute, ComStmtPrepare, ComStmtPrepareOk, FieldFlags,
     Status,
 };
 use crate::mysql::{MySql, MySqlArguments, MySqlCursor, MySqlTypeInfo};
@@ -40,12 +40,12 @@ impl super::MySqlConnection {
     // Gets a cached prepared statement ID _or_ prepares the statement if not in the cache
     // At the end we should have [cache_statement] and [cache_statement_columns] filled
     async fn get_or_prepare(&mut self, query: &str) -> crate::Result<u32> {
-        if let Some(&id) = self.cache_statement.get(query) {
+        if let Some(id) = self.cache_statement.get(query) {
             Ok(id)
         } else {
             let stmt = self.prepare(query).await?;
 
-            self.cache_statement.insert(query.into(), stmt.statement_id);
+            let evicted = self.cache_statement.insert(query, stmt.statement_id);
 
             // COM_STMT_PREPARE returns the input columns
             // We make no use of that data, so cycle through and drop them
@@ -55,6 +55,12 @@ impl super::MySqlConnection {
             // We just drop these as we get these when we execute the query
             self.drop_column_defs(stmt.columns as usize).await?;
 
+            // The cache is full,close the least recently used statement on the server
+            // COM_STMT_CLOSE has no response
+            if let Some(statement_id) = evicted {
+                self.stream.send(ComStmtClose { statement_id }, true).await?;
+            }
+
             Ok(stmt.statement_id)
         }
     }
@@ -93,8 +99,9 @@ impl super::MySqlConnection {
         }
     }
 
-    async fn affected_rows(&mut self) -> crate::Result<u64> {
+    pub(super) async fn affected_rows(&mut self) -> crate::Result<u64> {
         let mut rows = 0;
+        let mut local_infile = false;
 
         loop {
             let id = self.stream.receive().await?[0];
@@ -123,10 +130,20 @@ impl super::MySqlConnection {
                     return self.stream.handle_err();
                 }
 
+                0xFB => {
+                    // LOCAL INFILE request of an 'LOAD DATA LOCAL INFILE' not run by load_data_local(),
+                    // send an empty file,the server answer with an OK or ERR packet,then reject the statement
+                    self.stream.send(protocol::LocalInfileData(&[]), false).await?;
+                    local_infile = true;
+                }
+
                 _ => {}
             }
         }
 
+        if local_infile {
+            return Err(protocol_err!("'LOAD DATA LOCAL INFILE' can not run by execute,use load_data_local()").into());
+        }
         Ok(rows)
     }
 
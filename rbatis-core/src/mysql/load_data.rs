@@ -0,0 +1,68 @@
+use crate::mysql::protocol::{Capabilities, ComQuery, LocalInfileData};
+use crate::mysql::MySqlConnection;
+
+/// flush the buffered file data when it is larger than this
+const LOAD_DATA_FLUSH_SIZE: usize = 64 * 1024;
+
+/// max length of an file data packet(less than the 16MB packet limit)
+const LOAD_DATA_PACKET_SIZE: usize = 0xFF_FF_00;
+
+impl MySqlConnection {
+    /// run an 'LOAD DATA LOCAL INFILE' statement and send the data chunks as the file content(in the format of the statement,
+    /// rows can be split across chunks),return the loaded rows.
+    /// an Err chunk end the file early and return the Err,the rows sent before it are loaded(run in an tx to rollback them).
+    /// the conn must be connected with the option '?local_infile=true'
+    pub async fn load_data_local<I>(&mut self, query: &str, chunks: I) -> crate::Result<u64>
+        where
+            I: IntoIterator<Item=crate::Result<Vec<u8>>>,
+    {
+        if !self.stream.capabilities.contains(Capabilities::LOCAL_FILES) {
+            return Err(crate::Error::from("[rbatis] load_data_local need the connect option: ?local_infile=true"));
+        }
+        self.stream.wait_until_ready().await?;
+        self.stream.is_ready = false;
+        self.stream.send(ComQuery { query }, true).await?;
+
+        let id = self.stream.receive().await?[0];
+        match id {
+            0xFB => {}
+            0xFF => return self.stream.handle_err(),
+            0x00 => {
+                // not an 'LOAD DATA LOCAL INFILE'
+                self.stream.handle_ok()?;
+                return Err(protocol_err!("load_data_local: the query must be 'LOAD DATA LOCAL INFILE'").into());
+            }
+            id => {
+                return Err(protocol_err!("load_data_local: unexpected packet: 0x{:02X},the query must be 'LOAD DATA LOCAL INFILE'", id).into());
+            }
+        }
+
+        let mut buffered = 0;
+        let mut chunk_err = None;
+        for chunk in chunks {
+            let chunk = match chunk {
+                Ok(v) => v,
+                Err(e) => {
+                    chunk_err = Some(e);
+                    break;
+                }
+            };
+            for packet in chunk.chunks(LOAD_DATA_PACKET_SIZE) {
+                self.stream.write(LocalInfileData(packet));
+                buffered += packet.len();
+            }
+            if buffered >= LOAD_DATA_FLUSH_SIZE {
+                self.stream.flush().await?;
+                buffered = 0;
+            }
+        }
+        // an empty packet end the file
+        self.stream.send(LocalInfileData(&[]), false).await?;
+
+        let affected_rows = self.affected_rows().await;
+        match chunk_err {
+            Some(e) => Err(e),
+            None => affected_rows,
+        }
+    }
+}
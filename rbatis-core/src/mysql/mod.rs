@@ -16,6 +16,7 @@ mod database;
 mod error;
 mod executor;
 mod io;
+mod load_data;
 mod protocol;
 mod row;
 mod rsa;
@@ -0,0 +1,14 @@
+use crate::io::BufMut;
+use crate::mysql::protocol::{Capabilities, Encode};
+
+// https://dev.mysql.com/doc/internals/en/com-query-response.html#local-infile-request
+// the content of the file is sent as raw packets,an empty packet ends the file
+#[derive(Debug)]
+pub struct LocalInfileData<'a>(pub &'a [u8]);
+
+impl Encode for LocalInfileData<'_> {
+    fn encode(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.put_bytes(self.0);
+    }
+}
+
@@ -13,16 +13,20 @@ pub(crate) use status::Status;
 
 mod com_ping;
 mod com_query;
+mod com_stmt_close;
 mod com_stmt_execute;
 mod com_stmt_prepare;
 mod handshake;
+mod local_infile;
 
 pub(crate) use quit::Quit;
 pub(crate) use com_ping::ComPing;
 pub(crate) use com_query::ComQuery;
+pub(crate) use com_stmt_close::ComStmtClose;
 pub(crate) use com_stmt_execute::{ComStmtExecute, Cursor};
 pub(crate) use com_stmt_prepare::ComStmtPrepare;
 pub(crate) use handshake::Handshake;
+pub(crate) use local_infile::LocalInfileData;
 
 mod auth_switch;
 mod column_count;
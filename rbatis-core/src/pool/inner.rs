@@ -3,7 +3,7 @@ use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures_core::task::{Poll, Waker};
@@ -17,6 +17,7 @@ use crate::{
 };
 
 use super::connection::{Floating, Idle, Live};
+use super::metrics::{PoolMetrics, PoolState};
 use super::Options;
 
 pub(crate) struct SharedPool<C> {
@@ -26,6 +27,7 @@ pub(crate) struct SharedPool<C> {
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
     options: Options,
+    metrics: PoolMetrics,
 }
 
 impl<C> SharedPool<C>
@@ -53,6 +55,10 @@ where
         self.is_closed.load(Ordering::Acquire)
     }
 
+    pub(super) fn state(&self) -> PoolState {
+        self.metrics.state(self.size(), self.options.max_size, self.num_idle() as u32)
+    }
+
     pub(super) async fn close(&self) {
         self.is_closed.store(true, Ordering::Release);
         while let Ok(_) = self.idle_conns.pop() {}
@@ -140,6 +146,7 @@ where
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
+            metrics: PoolMetrics::default(),
         };
 
         pool.init_min_connections().await?;
@@ -153,7 +160,19 @@ where
 
     pub(super) async fn acquire<'s>(&'s self) -> crate::Result<Floating<'s, Live<C>>> {
         let start = Instant::now();
+        let _pending = self.metrics.pending();
+        let result = self.acquire_conn(start).await;
+        match &result {
+            Ok(_) => self.metrics.record_acquired(start.elapsed()),
+            Err(Error::PoolTimedOut(_)) => self.metrics.record_timed_out(),
+            Err(_) => {}
+        }
+        result
+    }
+
+    async fn acquire_conn<'s>(&'s self, start: Instant) -> crate::Result<Floating<'s, Live<C>>> {
         let deadline = start + self.options.connect_timeout;
+        let mut attempt = 0;
 
         // Unless the pool has been closed ...
         while !self.is_closed() {
@@ -171,7 +190,12 @@ where
                 match self.connect(deadline, guard).await {
                     Ok(Some(conn)) => return Ok(conn),
                     // [size] is internally decremented on _retry_ and _error_
-                    Ok(None) => continue,
+                    Ok(None) => {
+                        // the database is unreachable(for example restarting),wait before the retry
+                        self.backoff(attempt, deadline).await?;
+                        attempt += 1;
+                        continue;
+                    }
                     Err(e) => return Err(e),
                 }
             }
@@ -188,15 +212,22 @@ where
     async fn init_min_connections(&mut self) -> crate::Result<()> {
         for _ in 0..self.options.min_size {
             let deadline = Instant::now() + self.options.connect_timeout;
+            let mut attempt = 0;
 
             // this guard will prevent us from exceeding `max_size`
             while let Some(guard) = self.try_increment_size() {
                 // [connect] will raise an error when past deadline
                 // [connect] returns None if its okay to retry
-                if let Some(conn) = self.connect(deadline, guard).await? {
-                    self.idle_conns
-                        .push(conn.into_idle().into_leakable())
-                        .expect("BUG: connection queue overflow in init_min_connections");
+                match self.connect(deadline, guard).await? {
+                    Some(conn) => {
+                        self.idle_conns
+                            .push(conn.into_idle().into_leakable())
+                            .expect("BUG: connection queue overflow in init_min_connections");
+                    }
+                    None => {
+                        self.backoff(attempt, deadline).await?;
+                        attempt += 1;
+                    }
                 }
             }
         }
@@ -204,6 +235,19 @@ where
         Ok(())
     }
 
+    /// wait the exponential backoff of the retry attempt(start from 0),
+    /// returns an error if `deadline` elapses before the retry
+    async fn backoff(&self, attempt: u32, deadline: Instant) -> crate::Result<()> {
+        let delay = backoff_delay(self.options.reconnect_backoff, self.options.reconnect_max_backoff, attempt);
+        let now = Instant::now();
+        if now + delay >= deadline {
+            return Err(Error::PoolTimedOut(None));
+        }
+        log::info!("[rbatis] database is unreachable,reconnect after {:?}(retry {})", delay, attempt + 1);
+        sleep(delay).await;
+        Ok(())
+    }
+
     async fn connect<'s>(
         &'s self,
         deadline: Instant,
@@ -239,6 +283,13 @@ where
     }
 }
 
+/// initial * 2^attempt,at most max
+fn backoff_delay(initial: Duration, max: Duration, attempt: u32) -> Duration {
+    initial
+        .checked_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+        .map_or(max, |delay| cmp::min(delay, max))
+}
+
 // NOTE: Function names here are bizzare. Helpful help would be appreciated.
 
 fn is_beyond_lifetime<C>(live: &Live<C>, options: &Options) -> bool {
@@ -369,3 +420,20 @@ impl Drop for DecrementSizeGuard<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::pool::inner::backoff_delay;
+
+    #[test]
+    fn test_backoff_delay() {
+        let initial = Duration::from_millis(100);
+        let max = Duration::from_secs(5);
+        assert_eq!(backoff_delay(initial, max, 0), Duration::from_millis(100));
+        assert_eq!(backoff_delay(initial, max, 3), Duration::from_millis(800));
+        assert_eq!(backoff_delay(initial, max, 6), max);
+        assert_eq!(backoff_delay(initial, max, 64), max);
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// upper bounds(millisecond) of the acquire wait time buckets,the last bucket is the waits beyond 5000ms
+pub const WAIT_BUCKETS_MS: [u64; 9] = [1, 5, 10, 25, 50, 100, 500, 1000, 5000];
+
+/// an snapshot of the pool,for example alert when idle is 0 and pending is rising
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct PoolState {
+    /// the connections managed by the pool(active + idle)
+    pub size: u32,
+    pub max_size: u32,
+    /// the connections in use
+    pub active: u32,
+    pub idle: u32,
+    /// the acquires waiting for an connection now
+    pub pending: u32,
+    /// the acquires succeed
+    pub acquired: u64,
+    /// the acquires fail by connect_timeout
+    pub timed_out: u64,
+    /// the wait time of the succeed acquires
+    pub wait_time: WaitHistogram,
+}
+
+/// histogram of the acquire wait time
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct WaitHistogram {
+    /// the upper bound(millisecond) of every bucket(not cumulative),counts have one more bucket for the waits beyond the last bound
+    pub bounds_ms: Vec<u64>,
+    pub counts: Vec<u64>,
+    pub sum_ms: u64,
+    pub max_ms: u64,
+}
+
+impl WaitHistogram {
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// mean wait time(millisecond),0 if no acquire
+    pub fn mean_ms(&self) -> u64 {
+        let count = self.count();
+        if count == 0 {
+            return 0;
+        }
+        self.sum_ms / count
+    }
+}
+
+/// the counters of an pool,updated by acquire
+#[derive(Default)]
+pub(crate) struct PoolMetrics {
+    pending: AtomicU32,
+    acquired: AtomicU64,
+    timed_out: AtomicU64,
+    buckets: [AtomicU64; WAIT_BUCKETS_MS.len() + 1],
+    sum_ms: AtomicU64,
+    max_ms: AtomicU64,
+}
+
+impl PoolMetrics {
+    /// count an pending acquire until the guard is dropped(the acquire future can be cancelled)
+    pub(crate) fn pending(&self) -> PendingGuard<'_> {
+        self.pending.fetch_add(1, Ordering::AcqRel);
+        PendingGuard { pending: &self.pending }
+    }
+
+    pub(crate) fn record_acquired(&self, wait: Duration) {
+        let ms = wait.as_millis() as u64;
+        let index = WAIT_BUCKETS_MS.iter().position(|bound| ms <= *bound).unwrap_or(WAIT_BUCKETS_MS.len());
+        self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        self.sum_ms.fetch_add(ms, Ordering::Relaxed);
+        self.max_ms.fetch_max(ms, Ordering::Relaxed);
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_timed_out(&self) {
+        self.timed_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn state(&self, size: u32, max_size: u32, idle: u32) -> PoolState {
+        PoolState {
+            size,
+            max_size,
+            active: size.saturating_sub(idle),
+            idle,
+            pending: self.pending.load(Ordering::Acquire),
+            acquired: self.acquired.load(Ordering::Relaxed),
+            timed_out: self.timed_out.load(Ordering::Relaxed),
+            wait_time: WaitHistogram {
+                bounds_ms: WAIT_BUCKETS_MS.to_vec(),
+                counts: self.buckets.iter().map(|x| x.load(Ordering::Relaxed)).collect(),
+                sum_ms: self.sum_ms.load(Ordering::Relaxed),
+                max_ms: self.max_ms.load(Ordering::Relaxed),
+            },
+        }
+    }
+}
+
+pub(crate) struct PendingGuard<'a> {
+    pending: &'a AtomicU32,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.pending.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::pool::metrics::PoolMetrics;
+
+    #[test]
+    fn test_pool_metrics() {
+        let metrics = PoolMetrics::default();
+        let guard = metrics.pending();
+        assert_eq!(metrics.state(2, 10, 1).pending, 1);
+        drop(guard);
+        metrics.record_acquired(Duration::from_millis(0));
+        metrics.record_acquired(Duration::from_millis(30));
+        metrics.record_acquired(Duration::from_secs(6));
+        metrics.record_timed_out();
+        let state = metrics.state(2, 10, 1);
+        assert_eq!(state.active, 1);
+        assert_eq!(state.pending, 0);
+        assert_eq!(state.acquired, 3);
+        assert_eq!(state.timed_out, 1);
+        assert_eq!(state.wait_time.counts, vec![1, 0, 0, 0, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(state.wait_time.max_ms, 6000);
+        assert_eq!(state.wait_time.mean_ms(), 2010);
+    }
+}
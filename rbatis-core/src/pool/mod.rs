@@ -16,9 +16,11 @@ use self::options::Options;
 mod connection;
 mod executor;
 mod inner;
+mod metrics;
 mod options;
 
 pub use self::connection::PoolConnection;
+pub use self::metrics::{PoolState, WaitHistogram, WAIT_BUCKETS_MS};
 pub use self::options::Builder;
 
 /// A pool of database connections.
@@ -82,6 +84,11 @@ where
         self.0.is_closed()
     }
 
+    /// Returns the connection url of the pool.
+    pub fn url(&self) -> &str {
+        self.0.url()
+    }
+
     /// Returns the number of connections currently being managed by the pool.
     pub fn size(&self) -> u32 {
         self.0.size()
@@ -92,6 +99,11 @@ where
         self.0.num_idle()
     }
 
+    /// Returns the active/idle connections,the pending acquires and the acquire wait time histogram.
+    pub fn state(&self) -> PoolState {
+        self.0.state()
+    }
+
     /// Returns the configured maximum pool size.
     pub fn max_size(&self) -> u32 {
         self.0.options().max_size
@@ -35,6 +35,9 @@ where
                 idle_timeout: None,
                 // If true, test the health of a connection on acquire
                 test_on_acquire: true,
+                // retry connecting after 100ms,200ms,400ms ... at most 5 seconds(until connect_timeout)
+                reconnect_backoff: Duration::from_millis(100),
+                reconnect_max_backoff: Duration::from_secs(5),
             },
         }
     }
@@ -107,6 +110,22 @@ where
         self
     }
 
+    /// Set the exponential backoff of reconnecting when the database is unreachable(for example restarting),
+    /// the first retry wait `initial`,every retry double the wait until `max`.
+    ///
+    /// The broken idle connections are discarded by [`test_on_acquire`] and new connections are opened
+    /// until [`connect_timeout`] elapses,so an transient outage heal itself.
+    ///
+    /// Defaults to 100ms and 5s.
+    ///
+    /// [`test_on_acquire`]: #method.test_on_acquire
+    /// [`connect_timeout`]: #method.connect_timeout
+    pub fn reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.options.reconnect_backoff = initial;
+        self.options.reconnect_max_backoff = max;
+        self
+    }
+
     /// Spin up the connection pool.
     ///
     /// If [`min_size`] was set to a non-zero value, that many connections will be immediately
@@ -139,4 +158,6 @@ pub(crate) struct Options {
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub test_on_acquire: bool,
+    pub reconnect_backoff: Duration,
+    pub reconnect_max_backoff: Duration,
 }
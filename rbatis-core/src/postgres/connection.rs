@@ -6,6 +6,7 @@ use std::sync::Arc;
 use futures_core::future::BoxFuture;
 use futures_util::TryFutureExt;
 
+use crate::cache::StatementCache;
 use crate::connection::{Connect, Connection};
 use crate::executor::Executor;
 
@@ -85,7 +86,7 @@ pub struct PgConnection {
     pub(super) is_ready: bool,
 
     // cache query -> statement ID
-    pub(super) cache_statement_id: HashMap<Box<str>, StatementId>,
+    pub(super) cache_statement_id: StatementCache<StatementId>,
 
     // cache statement ID -> statement description
     pub(super) cache_statement: HashMap<StatementId, Arc<Statement>>,
@@ -264,7 +265,7 @@ impl PgConnection {
             is_ready: true,
             cache_type_oid: HashMap::new(),
             cache_type_name: HashMap::new(),
-            cache_statement_id: HashMap::with_capacity(10),
+            cache_statement_id: StatementCache::from_url(&url),
             cache_statement: HashMap::with_capacity(10),
             process_id: key_data.process_id,
             secret_key: key_data.secret_key,
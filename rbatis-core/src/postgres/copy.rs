@@ -0,0 +1,67 @@
+use crate::postgres::protocol::{self, CommandComplete, Message};
+use crate::postgres::PgConnection;
+
+/// flush the buffered COPY data when it is larger than this
+const COPY_FLUSH_SIZE: usize = 64 * 1024;
+
+impl PgConnection {
+    /// run an 'COPY ... FROM STDIN' statement and send the data chunks(in the format of the statement,
+    /// rows can be split across chunks),return the copied rows.
+    /// an Err chunk abort the COPY(CopyFail),no row is copied
+    pub async fn copy_in<I>(&mut self, query: &str, chunks: I) -> crate::Result<u64>
+        where
+            I: IntoIterator<Item=crate::Result<Vec<u8>>>,
+    {
+        self.wait_until_ready().await?;
+        self.write_simple_query(query);
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        match self.stream.receive().await? {
+            Message::CopyInResponse => {}
+            message => {
+                return Err(protocol_err!("copy_in: unexpected message: {:?},the query must be 'COPY ... FROM STDIN'", message).into());
+            }
+        }
+
+        let mut buffered = 0;
+        for chunk in chunks {
+            let chunk = match chunk {
+                Ok(v) => v,
+                Err(e) => {
+                    self.stream.write(protocol::CopyFail(&e.to_string()));
+                    self.stream.flush().await?;
+                    //consume the ErrorResponse of the server,the ReadyForQuery is consumed by the next command
+                    let _ = self.wait_until_ready().await;
+                    return Err(e);
+                }
+            };
+            if chunk.is_empty() {
+                continue;
+            }
+            self.stream.write(protocol::CopyData(&chunk));
+            buffered += chunk.len();
+            if buffered >= COPY_FLUSH_SIZE {
+                self.stream.flush().await?;
+                buffered = 0;
+            }
+        }
+        self.stream.write(protocol::CopyDone);
+        self.stream.flush().await?;
+
+        let mut affected_rows = 0;
+        loop {
+            match self.stream.receive().await? {
+                Message::CommandComplete => {
+                    affected_rows = CommandComplete::read(self.stream.buffer())?.affected_rows;
+                }
+                Message::ReadyForQuery => {
+                    self.is_ready = true;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(affected_rows)
+    }
+}
@@ -11,11 +11,14 @@ use crate::postgres::{PgArguments, PgConnection, PgRow, Postgres};
 use crate::postgres::protocol::{DataRow, Message, ReadyForQuery, RowDescription};
 use crate::postgres::row::Statement;
 use crate::decode::json_decode;
+use crate::row::Row as _;
 
 pub struct PgCursor<'c, 'q> {
     source: ConnectionSource<'c, PgConnection>,
     query: Option<(&'q str, Option<PgArguments>)>,
     statement: Arc<Statement>,
+    //count of result sets,for multi result sets
+    result_sets: usize,
 }
 
 impl crate::cursor::private::Sealed for PgCursor<'_, '_> {}
@@ -32,6 +35,7 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self {
             source: ConnectionSource::Pool(pool.clone()),
             statement: Arc::default(),
+            result_sets: 0,
             query: Some(query.into_parts()),
         }
     }
@@ -45,6 +49,7 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self {
             source: ConnectionSource::ConnectionRef(conn),
             statement: Arc::default(),
+            result_sets: 0,
             query: Some(query.into_parts()),
         }
     }
@@ -66,20 +71,74 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Box::pin(async move {
             let mut arr = vec![];
             while let Some(row) = self.next().await? as Option<PgRow<'_>> {
-                let mut m = serde_json::Map::new();
-                let keys = row.statement.names.keys();
-                for x in keys {
-                    let key = x.to_string();
-                    let v: serde_json::Value = row.json_decode_impl(key.as_str()).unwrap();
-                    m.insert(key, v);
-                }
-                arr.push(serde_json::Value::Object(m));
+                arr.push(row_json(&row));
+            }
+            return Ok(arr);
+        })
+    }
+
+    fn next_json(&mut self) -> BoxFuture<'_, Result<Option<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            match self.next().await? as Option<PgRow<'_>> {
+                Some(row) => Ok(Some(row_json(&row))),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn fetch_json_values(&mut self) -> BoxFuture<'_, Result<Vec<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            let mut arr = vec![];
+            while let Some(row) = self.next().await? as Option<PgRow<'_>> {
+                arr.push(row_values(&row));
             }
             return Ok(arr);
         })
     }
+
+    fn fetch_json_sets(&mut self) -> BoxFuture<'_, Result<Vec<Vec<serde_json::Value>>, crate::Error>> {
+        Box::pin(async move {
+            let mut sets: Vec<Vec<serde_json::Value>> = vec![];
+            loop {
+                let item = match self.next().await? as Option<PgRow<'_>> {
+                    Some(row) => row_json(&row),
+                    None => break,
+                };
+                let index = self.result_sets.max(1) - 1;
+                while sets.len() <= index {
+                    sets.push(vec![]);
+                }
+                sets[index].push(item);
+            }
+            //result sets without rows
+            while sets.len() < self.result_sets {
+                sets.push(vec![]);
+            }
+            return Ok(sets);
+        })
+    }
 }
 
+fn row_json(row: &PgRow<'_>) -> serde_json::Value {
+    let mut m = serde_json::Map::new();
+    let keys = row.statement.names.keys();
+    for x in keys {
+        let key = x.to_string();
+        let v: serde_json::Value = row.json_decode_impl(key.as_str()).unwrap();
+        m.insert(key, v);
+    }
+    serde_json::Value::Object(m)
+}
+
+
+fn row_values(row: &PgRow<'_>) -> serde_json::Value {
+    let mut arr = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let v: serde_json::Value = row.json_decode_impl(index).unwrap();
+        arr.push(v);
+    }
+    serde_json::Value::Array(arr)
+}
 
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut PgCursor<'c, 'q>,
@@ -96,6 +155,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
         if let Some(statement) = statement {
             // A prepared statement will re-use the previous column map
             cursor.statement = Arc::clone(&conn.cache_statement[&statement]);
+            // and have only one result set
+            cursor.result_sets = 1;
         }
 
         // A non-prepared query must be described each time
@@ -106,7 +167,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
         match conn.stream.receive().await? {
             // Indicates that a phase of the extended query flow has completed
             // We as rbatis_core don't generally care as long as it is happening
-            Message::ParseComplete | Message::BindComplete => {}
+            Message::ParseComplete | Message::BindComplete | Message::CloseComplete => {}
 
             // Indicates that _a_ query has finished executing
             Message::CommandComplete => {}
@@ -127,6 +188,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     conn.parse_row_description(rd, Default::default(), None, false)
                         .await?,
                 );
+                cursor.result_sets += 1;
             }
 
             Message::DataRow => {
@@ -1,7 +1,7 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
-use crate::error::DatabaseError;
+use crate::error::{ConstraintKind, ConstraintViolation, DatabaseError, find_between};
 use crate::postgres::protocol::Response;
 
 #[derive(Debug)]
@@ -36,6 +36,30 @@ impl DatabaseError for PgError {
         self.0.constraint.as_ref().map(|s| &**s)
     }
 
+    fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        let kind = match self.0.code.as_ref() {
+            "23505" => ConstraintKind::Unique,
+            "23502" => ConstraintKind::NotNull,
+            "23503" => ConstraintKind::ForeignKey,
+            "23514" => ConstraintKind::Check,
+            _ => return None,
+        };
+        let mut column = self.column_name().map(|x| x.to_string());
+        if column.is_none() {
+            // Key (email)=(a@b.c) already exists.
+            column = self.details()
+                .and_then(|d| find_between(d, "Key (", ")="))
+                .map(|x| x.to_string());
+        }
+        Some(ConstraintViolation {
+            kind,
+            constraint: self.constraint_name().map(|x| x.to_string()),
+            table: self.table_name().map(|x| x.to_string()),
+            column,
+            message: self.message().to_string(),
+        })
+    }
+
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
@@ -33,7 +33,7 @@ impl PgConnection {
         query: &str,
         args: &PgArguments,
     ) -> crate::Result<StatementId> {
-        if let Some(&id) = self.cache_statement_id.get(query) {
+        if let Some(id) = self.cache_statement_id.get(query) {
             Ok(id)
         } else {
             let id = StatementId(self.next_statement_id);
@@ -83,7 +83,12 @@ impl PgConnection {
             let statement = self.expect_row_desc(pd).await?;
 
             // cache statement ID and statement description
-            self.cache_statement_id.insert(query.into(), id);
+            if let Some(evicted) = self.cache_statement_id.insert(query, id) {
+                // The cache is full,close the least recently used statement,
+                // it is sent with the next command and the CloseComplete is skipped
+                self.cache_statement.remove(&evicted);
+                self.stream.write(protocol::Close::Statement(evicted));
+            }
             self.cache_statement.insert(id, Arc::new(statement));
 
             Ok(id)
@@ -222,7 +227,7 @@ impl PgConnection {
         self.stream.write(protocol::Sync);
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<()> {
         // depending on how the previous query finished we may need to continue
         // pulling messages from the stream until we receive a [ReadyForQuery] message
 
@@ -445,6 +450,7 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             match self.stream.receive().await? {
                 Message::ParseComplete
                 | Message::BindComplete
+                | Message::CloseComplete
                 | Message::NoData
                 | Message::EmptyQueryResponse
                 | Message::RowDescription => {}
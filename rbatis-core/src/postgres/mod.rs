@@ -14,6 +14,7 @@ pub use value::{PgData, PgValue};
 mod arguments;
 mod buffer;
 mod connection;
+mod copy;
 mod cursor;
 mod database;
 mod error;
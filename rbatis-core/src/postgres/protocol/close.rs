@@ -0,0 +1,44 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::{StatementId, Write};
+use byteorder::{ByteOrder, NetworkEndian};
+
+/// close an prepared statement,the server answer CloseComplete
+pub enum Close {
+    Statement(StatementId),
+}
+
+impl Write for Close {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'C');
+
+        let pos = buf.len();
+        buf.put_i32::<NetworkEndian>(0); // skip over len
+
+        match self {
+            Close::Statement(id) => {
+                buf.push(b'S');
+                id.write(buf);
+            }
+        };
+
+        // Write-back the len to the beginning of this frame
+        let len = buf.len() - pos;
+        NetworkEndian::write_i32(&mut buf[pos..], len as i32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Close, Write};
+    use crate::postgres::protocol::StatementId;
+
+    #[test]
+    fn it_writes_close_statement() {
+        let mut buf = Vec::new();
+        let m = Close::Statement(StatementId(1));
+
+        m.write(&mut buf);
+
+        assert_eq!(buf, b"C\x00\x00\x00\x18S__rbatis_core_statement_1\x00");
+    }
+}
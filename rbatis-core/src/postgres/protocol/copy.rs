@@ -0,0 +1,55 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+/// data of COPY FROM STDIN,rows can be split across messages
+pub struct CopyData<'a>(pub &'a [u8]);
+
+impl Write for CopyData<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'd');
+
+        // len + data
+        buf.put_i32::<NetworkEndian>((4 + self.0.len()) as i32);
+
+        buf.put_bytes(self.0);
+    }
+}
+
+pub struct CopyDone;
+
+impl Write for CopyDone {
+    #[inline]
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'c');
+        buf.put_i32::<NetworkEndian>(4);
+    }
+}
+
+/// abort the COPY,the server respond an ErrorResponse with the message
+pub struct CopyFail<'a>(pub &'a str);
+
+impl Write for CopyFail<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'f');
+
+        // len + message + nul
+        buf.put_i32::<NetworkEndian>((4 + self.0.len() + 1) as i32);
+
+        buf.put_str_nul(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyData, CopyDone, Write};
+
+    #[test]
+    fn it_writes_copy_data() {
+        let mut buf = Vec::new();
+        CopyData(b"1\ta\n").write(&mut buf);
+        CopyDone.write(&mut buf);
+
+        assert_eq!(buf, b"d\0\0\0\x081\ta\nc\0\0\0\x04");
+    }
+}
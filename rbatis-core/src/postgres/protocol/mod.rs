@@ -10,6 +10,8 @@ pub use type_id::TypeId;
 
 // REQUESTS
 mod bind;
+mod close;
+mod copy;
 mod describe;
 mod execute;
 mod parse;
@@ -24,6 +26,8 @@ mod sync;
 mod terminate;
 
 pub(crate) use bind::Bind;
+pub(crate) use close::Close;
+pub(crate) use copy::{CopyData, CopyDone, CopyFail};
 pub(crate) use describe::Describe;
 pub(crate) use execute::Execute;
 pub(crate) use parse::Parse;
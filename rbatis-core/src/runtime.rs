@@ -11,6 +11,7 @@ pub(crate) use async_std::{
     fs,
     future::timeout,
     io::prelude::ReadExt as AsyncReadExt,
+    io::prelude::WriteExt as AsyncWriteExt,
     io::{Read as AsyncRead, Write as AsyncWrite},
     net::TcpStream,
     task::sleep,
@@ -22,7 +23,7 @@ pub(crate) use async_std::{
 #[cfg(feature = "runtime-tokio")]
 pub(crate) use tokio::{
     fs,
-    io::{AsyncRead, AsyncReadExt, AsyncWrite},
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
     net::TcpStream,
     task::spawn,
     time::delay_for as sleep,
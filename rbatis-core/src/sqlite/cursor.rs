@@ -8,6 +8,7 @@ use crate::pool::Pool;
 use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteRow};
 use crate::sqlite::statement::Step;
 use crate::decode::json_decode;
+use crate::row::Row as _;
 
 pub struct SqliteCursor<'c, 'q> {
     pub(super) source: ConnectionSource<'c, SqliteConnection>,
@@ -70,22 +71,62 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
         Box::pin(async move {
             let mut arr = vec![];
             while let Some(row) = self.next().await? as Option<SqliteRow<'_>> {
-                let mut m = serde_json::Map::new();
-                //TODO is sqlite column is true?
-                let keys = row.values;
-                for x in 0..keys {
-                    let key = x.to_string();
-                    let v: serde_json::Value = row.json_decode_impl(key.as_str()).unwrap();
-                    m.insert(key, v);
-                }
-                arr.push(serde_json::Value::Object(m));
+                arr.push(row_json(&row));
             }
             return Ok(arr);
         })
     }
+
+    fn next_json(&mut self) -> BoxFuture<'_, Result<Option<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            match self.next().await? as Option<SqliteRow<'_>> {
+                Some(row) => Ok(Some(row_json(&row))),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn fetch_json_values(&mut self) -> BoxFuture<'_, Result<Vec<serde_json::Value>, crate::Error>> {
+        Box::pin(async move {
+            let mut arr = vec![];
+            while let Some(row) = self.next().await? as Option<SqliteRow<'_>> {
+                arr.push(row_values(&row));
+            }
+            return Ok(arr);
+        })
+    }
+
+    fn fetch_json_sets(&mut self) -> BoxFuture<'_, Result<Vec<Vec<serde_json::Value>>, crate::Error>> {
+        Box::pin(async move {
+            //sqlite run one statement at a time,so there is only one result set
+            let arr = self.fetch_json().await?;
+            return Ok(vec![arr]);
+        })
+    }
 }
 
 
+fn row_json(row: &SqliteRow<'_>) -> serde_json::Value {
+    let mut m = serde_json::Map::new();
+    //TODO is sqlite column is true?
+    let keys = row.values;
+    for x in 0..keys {
+        let key = x.to_string();
+        let v: serde_json::Value = row.json_decode_impl(key.as_str()).unwrap();
+        m.insert(key, v);
+    }
+    serde_json::Value::Object(m)
+}
+
+fn row_values(row: &SqliteRow<'_>) -> serde_json::Value {
+    let mut arr = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let v: serde_json::Value = row.json_decode_impl(index).unwrap();
+        arr.push(v);
+    }
+    serde_json::Value::Array(arr)
+}
+
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut SqliteCursor<'c, 'q>,
 ) -> crate::Result<Option<SqliteRow<'a>>> {
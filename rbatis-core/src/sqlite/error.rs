@@ -1,4 +1,4 @@
-use crate::error::DatabaseError;
+use crate::error::{ConstraintKind, ConstraintViolation, DatabaseError};
 
 use bitflags::_core::str::from_utf8_unchecked;
 use libsqlite3_sys::{sqlite3, sqlite3_errmsg, sqlite3_extended_errcode};
@@ -49,6 +49,45 @@ impl DatabaseError for SqliteError {
         Some(&self.code)
     }
 
+    fn constraint_violation(&self) -> Option<ConstraintViolation> {
+        // https://www.sqlite.org/rescode.html#extrc
+        let kind = match self.code.as_str() {
+            // SQLITE_CONSTRAINT_UNIQUE,SQLITE_CONSTRAINT_PRIMARYKEY
+            "2067" | "1555" => ConstraintKind::Unique,
+            // SQLITE_CONSTRAINT_NOTNULL
+            "1299" => ConstraintKind::NotNull,
+            // SQLITE_CONSTRAINT_FOREIGNKEY
+            "787" => ConstraintKind::ForeignKey,
+            // SQLITE_CONSTRAINT_CHECK
+            "275" => ConstraintKind::Check,
+            _ => return None,
+        };
+        let mut table = None;
+        let mut column = None;
+        let mut constraint = None;
+        // UNIQUE constraint failed: biz_activity.name / CHECK constraint failed: name
+        if let Some(index) = self.message.find("failed: ") {
+            let target = self.message[index + "failed: ".len()..].split(',').next().unwrap_or("").trim();
+            match target.find('.') {
+                _ if target.is_empty() => {}
+                Some(dot) if kind != ConstraintKind::Check => {
+                    table = Some(target[..dot].to_string());
+                    column = Some(target[dot + 1..].to_string());
+                }
+                _ => {
+                    constraint = Some(target.to_string());
+                }
+            }
+        }
+        Some(ConstraintViolation {
+            kind,
+            constraint,
+            table,
+            column,
+            message: self.message.clone(),
+        })
+    }
+
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
@@ -93,3 +132,15 @@ fn test_error_downcasting() {
         "SQLITE_ERR_SOMETHING"
     );
 }
+
+#[test]
+fn test_constraint_violation() {
+    let error = SqliteError {
+        code: "2067".into(),
+        message: "UNIQUE constraint failed: biz_activity.name".into(),
+    };
+    let v = crate::Error::from(error).constraint_violation().unwrap();
+    assert_eq!(v.kind, ConstraintKind::Unique);
+    assert_eq!(v.table, Some("biz_activity".to_string()));
+    assert_eq!(v.column, Some("name".to_string()));
+}
@@ -1,13 +1,16 @@
 extern crate proc_macro;
 
-use proc_macro2::{Ident, Span};
 use quote::quote;
 use quote::ToTokens;
 use syn;
 
 use crate::proc_macro::TokenStream;
 
-#[proc_macro_derive(CRUDEnable)]
+mod mapper;
+mod py_sql;
+mod sql;
+
+#[proc_macro_derive(CRUDEnable, attributes(crud))]
 pub fn hello_macro_derive(input: TokenStream) -> TokenStream {
     // 构建 Rust 代码所代表的语法树
     // 以便可以进行操作
@@ -17,27 +20,74 @@ pub fn hello_macro_derive(input: TokenStream) -> TokenStream {
     impl_macro(&ast)
 }
 
-///filter id_type
-fn find_id_type_ident(arg: &syn::Data) -> Ident {
-    let mut id_type = Ident::new("String", Span::call_site());
+/// py sql mapper fn,the py sql is checked at compile time,the args(except rb and tx_id) are the sql args by name,
+/// SELECT call rb.py_fetch(),others call rb.py_exec(),for example:
+///
+///  #[py_sql(RB, "
+///    SELECT * FROM biz_activity WHERE delete_flag = 0
+///    if name != null:
+///      AND name like #{name}")]
+///  async fn py_select(name: &str) -> Result<Vec<BizActivity>, rbatis_core::Error> {}
+#[proc_macro_attribute]
+pub fn py_sql(args: TokenStream, func: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(args as syn::AttributeArgs);
+    let func = syn::parse_macro_input!(func as mapper::MapperFn);
+    py_sql::impl_py_sql(args, func).into()
+}
+
+/// raw sql mapper fn,#{expr} are the prepare args,${expr} are joined into the sql,the expr must start with an arg,
+/// SELECT call rb.fetch_prepare(),others call rb.exec_prepare().
+/// the fn must return Result<T, rbatis_core::Error>.for example:
+///
+///  #[sql(RB, "select * from biz_activity where id = #{id}")]
+///  async fn find(id: &str) -> Result<BizActivity, rbatis_core::Error>;
+///
+///  #[sql(rb, "update biz_activity set name = #{arg.name} where id = #{arg.id}")]
+///  async fn update_name(rb: &Rbatis, tx_id: &str, arg: &BizActivity) -> Result<u64, rbatis_core::Error>;
+#[proc_macro_attribute]
+pub fn sql(args: TokenStream, func: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(args as syn::AttributeArgs);
+    let func = syn::parse_macro_input!(func as mapper::MapperFn);
+    sql::impl_sql(args, func).into()
+}
+
+///filter id_type,Option<T> will be unwrap to T
+fn find_id_type(arg: &syn::Data) -> proc_macro2::TokenStream {
+    for field in find_fields(arg) {
+        let field_name = format!("{}", field.ident.to_token_stream());
+        if field_name.eq("id") {
+            let ty = option_inner_type(&field.ty).unwrap_or(&field.ty);
+            return quote! { #ty };
+        }
+    }
+    quote! { String }
+}
+
+///Option<T> -> Some(T)
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Option" {
+            return None;
+        }
+        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                return Some(inner);
+            }
+        }
+    }
+    None
+}
+
+
+///collect named fields
+fn find_fields(arg: &syn::Data) -> Vec<&syn::Field> {
+    let mut fields = vec![];
     match &arg {
         syn::Data::Struct(ref data_struct) => match data_struct.fields {
-            // field: (0) a: String
             syn::Fields::Named(ref fields_named) => {
-                for (_, field) in fields_named.named.iter().enumerate() {
-                    //println!("named struct field: ({}) {}: {}", index, field_name, field.ty.to_token_stream());
-                    let field_name = format!("{}", field.ident.to_token_stream());
-                    if field_name.eq("id") {
-                        let ty = format!("{}", field.ty.to_token_stream());
-                        let mut inner_type = ty.trim().replace(" ", "").to_string();
-                        if inner_type.starts_with("Option<") {
-                            inner_type = inner_type.trim_start_matches("Option<").trim_end_matches(">").to_string();
-                        }
-                        //println!("id_type from:{}", &inner_type);
-                        id_type = Ident::new(inner_type.as_str(), Span::call_site());
-                        //println!("id_type:{}", &id_type);
-                        break;
-                    }
+                for field in fields_named.named.iter() {
+                    fields.push(field);
                 }
             }
             syn::Fields::Unnamed(_) => {}
@@ -45,26 +95,291 @@ fn find_id_type_ident(arg: &syn::Data) -> Ident {
         },
         _ => (),
     }
-    id_type
+    fields
+}
+
+///read #[crud(...)] items on attrs
+fn find_crud_metas(attrs: &Vec<syn::Attribute>) -> Vec<syn::NestedMeta> {
+    let mut metas = vec![];
+    for attr in attrs {
+        if !attr.path.is_ident("crud") {
+            continue;
+        }
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => {
+                for x in list.nested {
+                    metas.push(x);
+                }
+            }
+            Ok(_) => panic!("[rbatis] #[crud] attribute must be a list, for example: #[crud(unique)]"),
+            Err(e) => panic!("[rbatis] #[crud] attribute parse fail: {}", e),
+        }
+    }
+    metas
+}
+
+///column name of an field(same as the serde field name): #[serde(rename = "...")] or the field name without 'r#',
+///None if the field is #[serde(skip)]
+fn column_name(field: &syn::Field) -> Option<String> {
+    let mut name = format!("{}", field.ident.to_token_stream()).trim_start_matches("r#").to_string();
+    for attr in &field.attrs {
+        if !attr.path.is_ident("serde") {
+            continue;
+        }
+        let list = match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => list,
+            _ => continue,
+        };
+        for meta in list.nested.iter() {
+            match meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => return None,
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                    if let syn::Lit::Str(s) = &nv.lit {
+                        name = s.value();
+                    }
+                }
+                //rename(serialize = "..", deserialize = ".."),the column is read by the deserialize name
+                syn::NestedMeta::Meta(syn::Meta::List(rename)) if rename.path.is_ident("rename") => {
+                    for x in rename.nested.iter() {
+                        if let syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) = x {
+                            if let (true, syn::Lit::Str(s)) = (nv.path.is_ident("deserialize"), &nv.lit) {
+                                name = s.value();
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Some(name)
 }
 
+fn lit_str(lit: &syn::Lit) -> String {
+    match lit {
+        syn::Lit::Str(s) => s.value(),
+        _ => panic!("[rbatis] #[crud] attribute value must be a string literal!"),
+    }
+}
+
+///column metas: #[crud(comment = "...", unique, index, default_sql = "...", references = "table")]
+fn impl_columns_meta(arg: &syn::Data) -> proc_macro2::TokenStream {
+    let mut columns = vec![];
+    for field in find_fields(arg) {
+        let field_name = match column_name(field) {
+            Some(name) => name,
+            None => continue,
+        };
+        let mut comment = quote! { None };
+        let mut unique = false;
+        let mut index = false;
+        let mut default_sql = quote! { None };
+        let mut references = quote! { None };
+        for meta in find_crud_metas(&field.attrs) {
+            match &meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("unique") => {
+                    unique = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("index") => {
+                    index = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("comment") => {
+                    let v = lit_str(&nv.lit);
+                    comment = quote! { Some(#v.to_string()) };
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("default_sql") => {
+                    let v = lit_str(&nv.lit);
+                    default_sql = quote! { Some(#v.to_string()) };
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("references") => {
+                    let v = lit_str(&nv.lit);
+                    references = quote! { Some(#v.to_string()) };
+                }
+                //an typo(for example 'uniqe') must not be ignored silently
+                _ => {
+                    return syn::Error::new_spanned(&meta, format!("[rbatis] unknown #[crud] item on field '{}', expected: comment = \"..\", unique, index, default_sql = \"..\", references = \"..\"", field_name))
+                        .to_compile_error();
+                }
+            }
+        }
+        columns.push(quote! {
+            rbatis::crud::ColumnMeta {
+                name: #field_name.to_string(),
+                comment: #comment,
+                unique: #unique,
+                index: #index,
+                default_sql: #default_sql,
+                references: #references,
+            }
+        });
+    }
+    quote! {
+        fn table_columns_meta() -> Vec<rbatis::crud::ColumnMeta> {
+            vec![#(#columns),*]
+        }
+    }
+}
+
+///table fields: "id,name,..." from the column names of named fields(serde rename/skip are respected)
+fn impl_table_fields(arg: &syn::Data) -> proc_macro2::TokenStream {
+    let fields: Vec<String> = find_fields(arg).iter()
+        .filter_map(|x| column_name(x))
+        .collect();
+    let fields = fields.join(",");
+    if fields.is_empty() {
+        return quote! {};
+    }
+    quote! {
+        fn table_fields() -> String {
+            #fields.to_string()
+        }
+    }
+}
+
+///table name: #[crud(table_name = "...")] or snake name of struct
+fn impl_table_name(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    for meta in find_crud_metas(&ast.attrs) {
+        if let syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) = meta {
+            if nv.path.is_ident("table_name") {
+                let v = lit_str(&nv.lit);
+                return quote! {
+                    fn table_name() -> String {
+                        #v.to_string()
+                    }
+                };
+            }
+        }
+    }
+    //snake name is made at compile time,same as rbatis::utils::string_util::to_snake_name
+    let name = to_snake_name(&ast.ident.to_string());
+    quote! {
+        fn table_name() -> String {
+            #name.to_string()
+        }
+    }
+}
+
+fn to_snake_name(name: &str) -> String {
+    let chs_len = name.len();
+    let mut new_name = String::new();
+    for (index, x) in name.chars().enumerate() {
+        if x.is_uppercase() {
+            if index != 0 && (index + 1) != chs_len {
+                new_name.push_str("_");
+            }
+            new_name.push_str(x.to_lowercase().to_string().as_str());
+        } else {
+            new_name.push(x);
+        }
+    }
+    new_name
+}
+
+///profiles: #[crud(profile("summary", fields("id", "name")))]
+fn impl_profile_fields(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let field_names: Vec<String> = find_fields(&ast.data).iter()
+        .filter_map(|x| column_name(x))
+        .collect();
+    let mut profiles = vec![];
+    for meta in find_crud_metas(&ast.attrs) {
+        let list = match meta {
+            syn::NestedMeta::Meta(syn::Meta::List(list)) if list.path.is_ident("profile") => list,
+            _ => continue,
+        };
+        let mut name = String::new();
+        let mut fields = vec![];
+        for item in list.nested.iter() {
+            match item {
+                syn::NestedMeta::Lit(lit) => {
+                    name = lit_str(lit);
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(fields_list)) if fields_list.path.is_ident("fields") => {
+                    for x in fields_list.nested.iter() {
+                        match x {
+                            syn::NestedMeta::Lit(lit) => {
+                                let field = lit_str(lit);
+                                if !field_names.contains(&field) {
+                                    panic!("[rbatis] #[crud(profile)] field '{}' not exist in struct {}", field, ast.ident);
+                                }
+                                fields.push(field);
+                            }
+                            _ => panic!("[rbatis] #[crud(profile)] fields must be string literals!"),
+                        }
+                    }
+                }
+                _ => panic!("[rbatis] #[crud(profile)] format must be: profile(\"name\", fields(\"id\", \"name\"))"),
+            }
+        }
+        if name.is_empty() || fields.is_empty() {
+            panic!("[rbatis] #[crud(profile)] format must be: profile(\"name\", fields(\"id\", \"name\"))");
+        }
+        let fields = fields.join(",");
+        profiles.push(quote! { #name => Some(#fields.to_string()), });
+    }
+    if profiles.is_empty() {
+        return quote! {};
+    }
+    quote! {
+        fn profile_fields(profile: &str) -> Option<String> {
+            match profile {
+                #(#profiles)*
+                _ => None,
+            }
+        }
+    }
+}
+
+///column name consts: BizActivity::ID = "id",BizActivity::NAME = "name",the value is the column name(serde rename)
+fn impl_column_consts(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let mut consts = vec![];
+    for field in find_fields(&ast.data) {
+        let column = match column_name(field) {
+            Some(column) => column,
+            None => continue,
+        };
+        let field_name = format!("{}", field.ident.to_token_stream());
+        let field_name = field_name.trim_start_matches("r#").to_string();
+        let const_name = syn::Ident::new(&field_name.to_uppercase(), proc_macro2::Span::call_site());
+        consts.push(quote! {
+            pub const #const_name: &'static str = #column;
+        });
+    }
+    quote! {
+        impl #name {
+            #(#consts)*
+        }
+    }
+}
 
 fn impl_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
-    let id_type = find_id_type_ident(&ast.data);
+    let id_type = find_id_type(&ast.data);
+    let table_name = impl_table_name(ast);
+    let table_fields = impl_table_fields(&ast.data);
+    let columns_meta = impl_columns_meta(&ast.data);
+    let profile_fields = impl_profile_fields(ast);
+    let column_consts = impl_column_consts(ast);
     let gen = quote! {
         impl CRUDEnable for #name {
             //识别的表id字段类型
             type IdType = #id_type;
 
             //识别的表名
-            fn table_name() -> String {
-                 let mut name = stringify!(#name).to_string();
-                 let names: Vec<&str> = name.split("::").collect();
-                 name = names.get(names.len() - 1).unwrap().to_string();
-                 return rbatis::utils::string_util::to_snake_name(&name);
-            }
+            #table_name
+
+            //识别的表字段
+            #table_fields
+
+            //字段元数据(注释,唯一,索引,默认值)
+            #columns_meta
+
+            //字段配置(profile)
+            #profile_fields
         }
+
+        //字段名常量
+        #column_consts
     };
     gen.into()
 }
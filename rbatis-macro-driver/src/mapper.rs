@@ -0,0 +1,118 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use quote::ToTokens;
+use syn;
+use syn::parse::{Parse, ParseStream};
+
+///an mapper fn,the body is generated so it must be empty(or omitted),for example:
+///  async fn find(id: &str) -> Result<BizActivity, rbatis_core::Error>;
+///  async fn find(id: &str) -> Result<BizActivity, rbatis_core::Error> {}
+pub struct MapperFn {
+    pub attrs: Vec<syn::Attribute>,
+    pub vis: syn::Visibility,
+    pub sig: syn::Signature,
+}
+
+impl Parse for MapperFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis: syn::Visibility = input.parse()?;
+        let sig: syn::Signature = input.parse()?;
+        if input.peek(syn::Token![;]) {
+            input.parse::<syn::Token![;]>()?;
+        } else {
+            let block = input.parse::<syn::Block>()?;
+            if !block.stmts.is_empty() {
+                return Err(syn::Error::new_spanned(&block, "[rbatis] mapper fn body must be empty(or omitted),it is generated by the macro"));
+            }
+        }
+        if sig.asyncness.is_none() {
+            return Err(syn::Error::new_spanned(&sig.fn_token, "[rbatis] mapper fn must be async"));
+        }
+        Ok(Self { attrs, vis, sig })
+    }
+}
+
+///the args of an mapper fn
+pub(crate) struct MapperArgs {
+    ///the tx_id arg,or "" if not have
+    pub tx_id: TokenStream,
+    ///the sql args(all args except rb and tx_id)
+    pub args: Vec<syn::Ident>,
+}
+
+///parse (rb, "sql") of the attribute
+pub(crate) fn parse_args(args: &syn::AttributeArgs, name: &str) -> syn::Result<(TokenStream, String, Span)> {
+    let usage = format!("[rbatis] usage: #[{}(rb, \"sql\")]", name);
+    if args.len() != 2 {
+        return Err(syn::Error::new(Span::call_site(), usage));
+    }
+    let rb = match &args[0] {
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.to_token_stream(),
+        x => return Err(syn::Error::new_spanned(x, usage)),
+    };
+    match &args[1] {
+        syn::NestedMeta::Lit(syn::Lit::Str(s)) => Ok((rb, s.value(), s.span())),
+        x => Err(syn::Error::new_spanned(x, usage)),
+    }
+}
+
+///the statement is an query
+pub(crate) fn is_select(sql: &str) -> bool {
+    let sql = sql.trim_start().to_uppercase();
+    sql.starts_with("SELECT") || sql.starts_with("WITH")
+}
+
+///find the tx_id and sql args of the fn,the rb arg(if rb is an arg) is skipped
+pub(crate) fn find_args(rb: &TokenStream, func: &MapperFn) -> syn::Result<MapperArgs> {
+    let rb_name = rb.to_string();
+    let mut tx_id = quote! { "" };
+    let mut args = vec![];
+    for input in &func.sig.inputs {
+        let pat = match input {
+            syn::FnArg::Typed(pat) => pat,
+            syn::FnArg::Receiver(_) => continue,
+        };
+        let ident = match &*pat.pat {
+            syn::Pat::Ident(ident) => &ident.ident,
+            x => return Err(syn::Error::new_spanned(x, "[rbatis] the arg must be an ident")),
+        };
+        let name = ident.to_string();
+        if name == rb_name {
+            continue;
+        }
+        if name == "tx_id" {
+            tx_id = quote! { #ident };
+            continue;
+        }
+        args.push(ident.clone());
+    }
+    Ok(MapperArgs { tx_id, args })
+}
+
+///make the fn,the body return Result<T, rbatis_core::Error>,so the fn must return Result(an compile error if not)
+pub(crate) fn impl_fn_body(func: &MapperFn, body: TokenStream) -> TokenStream {
+    let attrs = &func.attrs;
+    let vis = &func.vis;
+    let sig = &func.sig;
+    let is_result = match &sig.output {
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::Path(p) => p.path.segments.last().map(|x| x.ident == "Result").unwrap_or(false),
+            _ => false,
+        },
+        syn::ReturnType::Default => false,
+    };
+    if !is_result {
+        let msg = "[rbatis] mapper fn must return Result<T, rbatis_core::Error>";
+        return match &sig.output {
+            syn::ReturnType::Type(_, ty) => syn::Error::new_spanned(ty, msg).to_compile_error(),
+            syn::ReturnType::Default => syn::Error::new_spanned(sig, msg).to_compile_error(),
+        };
+    }
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            #body
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn;
+
+use crate::mapper::{find_args, impl_fn_body, is_select, MapperFn, parse_args};
+
+///#[py_sql(rb, "py sql")]: check the py sql at compile time,and make the fn body call rb.py_fetch() or rb.py_exec()
+pub fn impl_py_sql(args: syn::AttributeArgs, func: MapperFn) -> TokenStream {
+    let (rb, sql, sql_span) = match parse_args(&args, "py_sql") {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error(),
+    };
+    if let Err(e) = check_py_sql(&sql) {
+        return syn::Error::new(sql_span, format!("[rbatis] py_sql check fail: {}", e)).to_compile_error();
+    }
+    let method = if is_select(&sql) { quote! { py_fetch } } else { quote! { py_exec } };
+    let fn_args = match find_args(&rb, &func) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error(),
+    };
+    //all args except rb and tx_id are put into the json arg(by arg name)
+    let tx_id = fn_args.tx_id;
+    let inserts = fn_args.args.iter().map(|ident| {
+        let name = ident.to_string();
+        quote! {
+            rbatis_args.insert(#name.to_string(), serde_json::to_value(&#ident).unwrap_or(serde_json::Value::Null));
+        }
+    });
+    let body = quote! {
+        let rbatis_tx_id: &str = #tx_id;
+        let mut rbatis_args = serde_json::Map::new();
+        #(#inserts)*
+        let rbatis_args = serde_json::Value::Object(rbatis_args);
+        #rb.#method(rbatis_tx_id, #sql, &rbatis_args).await
+    };
+    impl_fn_body(&func, body)
+}
+
+///check the py sql syntax: tags(if,elif,else,for,trim,choose,when,otherwise,bind,set,where),let,expressions and #{} ${} placeholders
+pub(crate) fn check_py_sql(py: &str) -> Result<(), String> {
+    for (index, line) in py.lines().enumerate() {
+        let line_no = index + 1;
+        let trim = line.trim();
+        if trim.is_empty() {
+            continue;
+        }
+        check_placeholders(trim).map_err(|e| format!("line {}: {}", line_no, e))?;
+        if trim.starts_with("let ") {
+            check_bind(&trim["let ".len()..]).map_err(|e| format!("line {}: {}", line_no, e))?;
+            continue;
+        }
+        if !trim.ends_with(':') {
+            continue;
+        }
+        let head = trim[..trim.len() - 1].trim();
+        let (tag, rest) = match head.find(' ') {
+            Some(i) => (&head[..i], head[i..].trim()),
+            None => (head, ""),
+        };
+        let result = match tag {
+            "if" | "elif" | "when" => check_expr(rest),
+            "for" => {
+                match rest.find(" in ") {
+                    Some(i) if !rest[..i].trim().is_empty() && !rest[i + 4..].trim().is_empty() => check_expr(rest[i + 4..].trim()),
+                    _ => Err(format!("'for {}' must be 'for item in collection'", rest)),
+                }
+            }
+            "trim" => {
+                if rest.len() >= 2 && rest.starts_with('\'') && rest.ends_with('\'') {
+                    Ok(())
+                } else {
+                    Err(format!("'trim {}' must be trim 'value'", rest))
+                }
+            }
+            "bind" => check_bind(rest),
+            "else" | "choose" | "otherwise" | "set" | "where" => Ok(()),
+            _ => Err(format!("unknow tag: {}", head)),
+        };
+        result.map_err(|e| format!("line {}: {}", line_no, e))?;
+    }
+    Ok(())
+}
+
+///let/bind must be 'name = value'
+pub(crate) fn check_bind(express: &str) -> Result<(), String> {
+    match express.find('=') {
+        Some(i) if !express[..i].trim().is_empty() && !express[i + 1..].starts_with('=') => check_expr(&express[i + 1..]),
+        _ => Err(format!("'{}' must be 'name = value'", express)),
+    }
+}
+
+///every #{ and ${ must be closed and not empty
+pub(crate) fn check_placeholders(line: &str) -> Result<(), String> {
+    let mut rest = line;
+    loop {
+        let start = match (rest.find("#{"), rest.find("${")) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => break,
+        };
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| format!("placeholder not closed: {}", &rest[start..]))?;
+        let name = after[..end].trim();
+        if name.is_empty() {
+            return Err("empty placeholder".to_string());
+        }
+        check_expr(name)?;
+        rest = &after[end + 1..];
+    }
+    Ok(())
+}
+
+///the expression is not empty,the brackets and quotes are closed,and not end with an operator
+pub(crate) fn check_expr(expr: &str) -> Result<(), String> {
+    let expr = expr.trim();
+    if expr.is_empty() {
+        return Err("empty expression".to_string());
+    }
+    let mut stack = vec![];
+    let mut quote: Option<char> = None;
+    for c in expr.chars() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '(' | '[' => stack.push(c),
+            ')' => {
+                if stack.pop() != Some('(') {
+                    return Err(format!("unexpected ')' in expression: {}", expr));
+                }
+            }
+            ']' => {
+                if stack.pop() != Some('[') {
+                    return Err(format!("unexpected ']' in expression: {}", expr));
+                }
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return Err(format!("quote not closed in expression: {}", expr));
+    }
+    if !stack.is_empty() {
+        return Err(format!("bracket not closed in expression: {}", expr));
+    }
+    for op in &["&&", "||", "==", "!=", ">=", "<=", "+", "-", "*", "/", "%", ">", "<", "!", "=", "&", "|"] {
+        if expr.ends_with(op) {
+            return Err(format!("expression can not end with '{}': {}", op, expr));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::py_sql::check_py_sql;
+
+    #[test]
+    fn test_check_py_sql() {
+        assert!(check_py_sql("SELECT * FROM biz_activity WHERE delete_flag = 0
+                  if name != null:
+                    AND name like #{name+'%'}
+                  for item in ids:
+                    #{item}").is_ok());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  if age > 30:
+                    age > 30
+                  elif age > 20:
+                    age > 20
+                  else:
+                    age <= 20").is_ok());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  let pattern = '%' + name + '%'
+                  AND name like #{pattern}").is_ok());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  let pattern =
+                  AND name like #{pattern}").is_err());
+        assert!(check_py_sql("SELECT * FROM biz_activity WHERE id = #{id").is_err());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  if (name != null:
+                    AND name = #{name}").is_err());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  iff name != null:
+                    AND name = #{name}").is_err());
+        assert!(check_py_sql("SELECT * FROM biz_activity
+                  if name == :
+                    AND name = #{name}").is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn;
+
+use crate::mapper::{find_args, impl_fn_body, is_select, MapperFn, parse_args};
+
+///an part of the raw sql
+#[derive(Debug, PartialEq)]
+pub(crate) enum SqlPart {
+    Text(String),
+    ///#{expr} is an prepare arg
+    Arg(String),
+    ///${expr} is joined into the sql
+    Raw(String),
+}
+
+///#[sql(rb, "raw sql")]: split the sql at compile time,#{expr} are the prepare args,
+///make the fn body call rb.fetch_prepare() or rb.exec_prepare()
+pub fn impl_sql(args: syn::AttributeArgs, func: MapperFn) -> TokenStream {
+    let (rb, sql, sql_span) = match parse_args(&args, "sql") {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error(),
+    };
+    let fn_args = match find_args(&rb, &func) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error(),
+    };
+    let parts = match split_sql(&sql) {
+        Ok(v) => v,
+        Err(e) => return syn::Error::new(sql_span, format!("[rbatis] sql check fail: {}", e)).to_compile_error(),
+    };
+    let mut pushes = vec![];
+    for part in parts {
+        let expr = match &part {
+            SqlPart::Text(text) => {
+                pushes.push(quote! { rbatis_sql.push_str(#text); });
+                continue;
+            }
+            SqlPart::Arg(expr) | SqlPart::Raw(expr) => expr,
+        };
+        let parsed: syn::Expr = match syn::parse_str(expr) {
+            Ok(v) => v,
+            Err(e) => return syn::Error::new(sql_span, format!("[rbatis] sql check fail: '{}' {}", expr, e)).to_compile_error(),
+        };
+        if let Some(root) = root_ident(&parsed) {
+            if !fn_args.args.iter().any(|x| *x == root) {
+                return syn::Error::new(sql_span, format!("[rbatis] sql check fail: '{}' is not an arg of the fn", root)).to_compile_error();
+            }
+        }
+        match part {
+            SqlPart::Arg(_) => pushes.push(quote! {
+                rbatis_sql.push_str(&rbatis_core::convert::StmtConvert::stmt_convert(&rbatis_driver_type, rbatis_args.len()));
+                rbatis_args.push(serde_json::to_value(&(#parsed)).unwrap_or(serde_json::Value::Null));
+            }),
+            _ => pushes.push(quote! {
+                rbatis_sql.push_str(&format!("{}", #parsed));
+            }),
+        }
+    }
+    let method = if is_select(&sql) { quote! { fetch_prepare } } else { quote! { exec_prepare } };
+    let tx_id = fn_args.tx_id;
+    let body = quote! {
+        let rbatis_tx_id: &str = #tx_id;
+        let rbatis_driver_type = #rb.driver_type()?;
+        let mut rbatis_sql = String::new();
+        let mut rbatis_args: Vec<serde_json::Value> = vec![];
+        #(#pushes)*
+        #rb.#method(rbatis_tx_id, &rbatis_sql, &rbatis_args).await
+    };
+    impl_fn_body(&func, body)
+}
+
+///split the sql by #{expr} and ${expr}
+pub(crate) fn split_sql(sql: &str) -> Result<Vec<SqlPart>, String> {
+    let mut parts = vec![];
+    let mut rest = sql;
+    loop {
+        let start = match (rest.find("#{"), rest.find("${")) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => break,
+        };
+        if start > 0 {
+            parts.push(SqlPart::Text(rest[..start].to_string()));
+        }
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| format!("placeholder not closed: {}", &rest[start..]))?;
+        let expr = after[..end].trim().to_string();
+        if expr.is_empty() {
+            return Err("empty placeholder".to_string());
+        }
+        if rest[start..].starts_with('#') {
+            parts.push(SqlPart::Arg(expr));
+        } else {
+            parts.push(SqlPart::Raw(expr));
+        }
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(SqlPart::Text(rest.to_string()));
+    }
+    Ok(parts)
+}
+
+///the root ident of an expr,for example: arg.name => arg
+fn root_ident(expr: &syn::Expr) -> Option<syn::Ident> {
+    match expr {
+        syn::Expr::Path(p) if p.path.segments.len() == 1 => Some(p.path.segments[0].ident.clone()),
+        syn::Expr::Field(f) => root_ident(&f.base),
+        syn::Expr::MethodCall(m) => root_ident(&m.receiver),
+        syn::Expr::Index(i) => root_ident(&i.expr),
+        syn::Expr::Paren(p) => root_ident(&p.expr),
+        syn::Expr::Reference(r) => root_ident(&r.expr),
+        syn::Expr::Unary(u) => root_ident(&u.expr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sql::{split_sql, SqlPart};
+
+    #[test]
+    fn test_split_sql() {
+        let parts = split_sql("select * from ${table} where id = #{arg.id} and name != ''").unwrap();
+        assert_eq!(parts, vec![
+            SqlPart::Text("select * from ".to_string()),
+            SqlPart::Raw("table".to_string()),
+            SqlPart::Text(" where id = ".to_string()),
+            SqlPart::Arg("arg.id".to_string()),
+            SqlPart::Text(" and name != ''".to_string()),
+        ]);
+        assert!(split_sql("select * from biz_activity where id = #{id").is_err());
+        assert!(split_sql("select * from biz_activity where id = #{ }").is_err());
+    }
+}
@@ -70,6 +70,8 @@ impl Py {
         let mut skip_line = -1;
         let mut space = -1;
         let mut line = -1;
+        //index of the elif/else nodes in pys
+        let mut branches = vec![];
         for x in ls {
             line += 1;
             if x.is_empty() {
@@ -171,15 +173,95 @@ impl Py {
 
             let node = Py::parser_node(x, *line_space_map.get(&line).unwrap() as usize)?;
 
-
+            if Py::is_branch(x) {
+                branches.push(pys.len());
+            }
             pys.push(node);
             //当前node
         }
-        return Ok(pys);
+        if branches.is_empty() {
+            return Ok(pys);
+        }
+        return Py::fold_if_branches(pys, &branches);
+    }
+
+    /// parser 'name = value' of let/bind,for example: let pattern = '%' + name + '%'
+    fn parser_bind(express: &str) -> Result<NodeType, rbatis_core::Error> {
+        let index = express.find("=");
+        if index.is_none() {
+            return Err(rbatis_core::Error::from("[rbatis] parser bind express fail,must be 'name = value':".to_string() + express));
+        }
+        let index = index.unwrap();
+        let name = express[..index].trim();
+        let value = express[index + 1..].trim();
+        if name.is_empty() || value.is_empty() || value.starts_with("=") || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(rbatis_core::Error::from("[rbatis] parser bind express fail,must be 'name = value':".to_string() + express));
+        }
+        return Ok(NodeType::NBind(BindNode {
+            name: name.to_string(),
+            value: value.to_string(),
+        }));
+    }
+
+    fn is_branch(x: &str) -> bool {
+        let trim_x = x.trim();
+        trim_x.ends_with(":") && (trim_x.starts_with("elif ") || trim_x[0..trim_x.len() - 1].trim() == "else")
+    }
+
+    /// fold if/elif/else into an choose node,for example:
+    ///   if a == 1:
+    ///     ...
+    ///   elif a == 2:
+    ///     ...
+    ///   else:
+    ///     ...
+    fn fold_if_branches(pys: Vec<NodeType>, branches: &Vec<usize>) -> Result<Vec<NodeType>, rbatis_core::Error> {
+        let mut result: Vec<NodeType> = vec![];
+        //the last node of result is an if chain
+        let mut in_chain = false;
+        for (index, node) in pys.into_iter().enumerate() {
+            if !branches.contains(&index) {
+                in_chain = match &node {
+                    NodeType::NIf(_) => true,
+                    _ => false,
+                };
+                result.push(node);
+                continue;
+            }
+            if !in_chain {
+                return Err(rbatis_core::Error::from("[rbatis] parser node fail,elif/else must after if or elif!"));
+            }
+            let last = result.last_mut().unwrap();
+            if let NodeType::NIf(if_node) = last {
+                *last = NodeType::NChoose(ChooseNode {
+                    when_nodes: Some(vec![NodeType::NWhen(WhenNode {
+                        childs: if_node.childs.clone(),
+                        test: if_node.test.clone(),
+                    })]),
+                    otherwise_node: None,
+                });
+            }
+            if let NodeType::NChoose(choose) = last {
+                match node {
+                    NodeType::NWhen(_) => {
+                        choose.when_nodes.as_mut().unwrap().push(node);
+                    }
+                    _ => {
+                        choose.otherwise_node = Some(Box::new(node));
+                        //else is the end of the chain
+                        in_chain = false;
+                    }
+                }
+            }
+        }
+        return Ok(result);
     }
 
     fn parser_node(x: &str, space: usize) -> Result<NodeType, rbatis_core::Error> {
         let mut trim_x = x.trim();
+        if trim_x.starts_with("let ") {
+            return Py::parser_bind(trim_x["let ".len()..].trim());
+        }
         if trim_x.ends_with(":") {
             trim_x = trim_x[0..trim_x.len() - 1].trim();
 
@@ -189,6 +271,16 @@ impl Py {
                     childs: vec![],
                     test: trim_x.to_string(),
                 }));
+            } else if trim_x.starts_with("elif ") {
+                trim_x = trim_x["elif ".len()..].trim();
+                return Ok(NodeType::NWhen(WhenNode {
+                    childs: vec![],
+                    test: trim_x.to_string(),
+                }));
+            } else if trim_x == "else" {
+                return Ok(NodeType::NOtherwise(OtherwiseNode {
+                    childs: vec![],
+                }));
             } else if trim_x.starts_with("for ") {
                 if !trim_x.contains(" in ") {
                     return Err(rbatis_core::Error::from("[rbatis] parser express fail:".to_string() + trim_x));
@@ -220,13 +312,13 @@ impl Py {
                 } else {
                     return Err(rbatis_core::Error::from("[rbatis] parser express fail:".to_string() + trim_x));
                 }
-            } else if trim_x.starts_with("choose ") {
+            } else if trim_x == "choose" || trim_x.starts_with("choose ") {
                 trim_x = trim_x["choose ".len()..].trim();
                 return Ok(NodeType::NChoose(ChooseNode {
                     when_nodes: None,
                     otherwise_node: None,
                 }));
-            } else if trim_x.starts_with("otherwise ") {
+            } else if trim_x == "otherwise" || trim_x.starts_with("otherwise ") {
                 trim_x = trim_x["otherwise ".len()..].trim();
                 return Ok(NodeType::NOtherwise(OtherwiseNode {
                     childs: vec![],
@@ -239,16 +331,13 @@ impl Py {
                 }));
             } else if trim_x.starts_with("bind ") {
                 trim_x = trim_x["bind ".len()..].trim();
-                return Ok(NodeType::NBind(BindNode {
-                    name: "".to_string(),
-                    value: "".to_string(),
-                }));
-            } else if trim_x.starts_with("set ") {
+                return Py::parser_bind(trim_x);
+            } else if trim_x == "set" || trim_x.starts_with("set ") {
                 trim_x = trim_x["set ".len()..].trim();
                 return Ok(NodeType::NSet(SetNode {
                     childs: vec![]
                 }));
-            } else if trim_x.starts_with("where ") {
+            } else if trim_x == "where" || trim_x.starts_with("where ") {
                 trim_x = trim_x["where ".len()..].trim();
                 return Ok(NodeType::NWhere(WhereNode {
                     childs: vec![]
@@ -404,4 +493,60 @@ pub fn bench_exec() {
     WHERE id  = '2';";
         let pys = Py::parser_and_cache(s);
     });
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_if_elif_else() {
+    let s = "
+    SELECT * FROM biz_activity WHERE
+    if age > 30:
+      age > 30
+    elif age > 20:
+      age > 20
+    else:
+      age <= 20
+    choose:
+      when name != null:
+        AND name = #{name}
+      otherwise:
+        AND name is null";
+    let pys = Py::parser(s).unwrap();
+    let mut engine = RbatisEngine::new();
+    for (age, expect) in vec![(40, "age > 30"), (25, "age > 20"), (10, "age <= 20")] {
+        let mut arg_array = vec![];
+        let mut env = json!({
+            "age": age,
+            "name": "a"
+        });
+        let r = crate::ast::node::node::do_child_nodes(&DriverType::Mysql, &pys, &mut env, &mut engine, &mut arg_array).unwrap();
+        println!("{}", r);
+        assert!(r.contains(expect));
+        assert!(r.contains("name = ?"));
+        assert!(!r.contains("is null"));
+    }
+    assert!(Py::parser("
+    SELECT * FROM biz_activity
+    elif age > 20:
+      age > 20").is_err());
+}
+
+#[test]
+pub fn test_let() {
+    let s = "
+    SELECT * FROM biz_activity WHERE delete_flag = 0
+    if name != null:
+      let pattern = '%' + name + '%'
+      AND name like #{pattern}";
+    let pys = Py::parser(s).unwrap();
+    let mut engine = RbatisEngine::new();
+    let mut arg_array = vec![];
+    let mut env = json!({
+        "name": "test",
+    });
+    let r = crate::ast::node::node::do_child_nodes(&DriverType::Mysql, &pys, &mut env, &mut engine, &mut arg_array).unwrap();
+    println!("{}", r);
+    assert_eq!(arg_array, vec![json!("%test%")]);
+    assert!(Py::parser("
+    SELECT * FROM biz_activity
+    let pattern == name").is_err());
+}
@@ -61,29 +61,50 @@ pub fn parser(xml_content: &str) -> HashMap<String, NodeType> {
 fn do_replace_include_node(arg: &mut HashMap<String, NodeType>) {
     let arg_clone = arg.clone();
     for (k, v) in arg {
-        let mut childs = v.childs_mut();
-        if childs.is_none() {
-            continue;
+        if let Some(childs) = v.childs_mut() {
+            let mut refs = vec![k.clone()];
+            loop_replace_include_node(childs, &arg_clone, &mut refs);
         }
-        let childs = childs.take().unwrap();
-        let mut include_nodes = Vec::new();
-        loop_find_include_node(childs, &mut include_nodes);
-        if include_nodes.is_empty() {
-            continue;
-        }
-        for item in include_nodes {
-            match item {
-                NodeType::NInclude(include) => {
-                    if include.refid.is_empty() {
-                        panic!("[rbatis] include node refid must have an value!");
-                    }
-                    let mut v = find_node(&arg_clone, &include.refid);
-                    if v.is_none() {
-                        panic!(format!("[rbatis] include node refid = '{}' not find!", &include.refid));
+    }
+}
+
+///replace every <include> (include in <if>,<where>,<choose> and the included <sql>) with the node of refid,
+///refs is the include path,panic if the include is circular
+fn loop_replace_include_node(m: &mut Vec<NodeType>, arg: &HashMap<String, NodeType>, refs: &mut Vec<String>) {
+    for x in m {
+        match x {
+            NodeType::NInclude(include) => {
+                if include.refid.is_empty() {
+                    panic!("[rbatis] include node refid must have an value!");
+                }
+                if refs.contains(&include.refid) {
+                    panic!(format!("[rbatis] include node refid = '{}' is circular: {} -> {}", &include.refid, refs.join(" -> "), &include.refid));
+                }
+                let mut v = match find_node(arg, &include.refid) {
+                    Some(v) => v,
+                    None => panic!(format!("[rbatis] include node refid = '{}' not find!", &include.refid)),
+                };
+                refs.push(include.refid.clone());
+                if let Some(childs) = v.childs_mut() {
+                    loop_replace_include_node(childs, arg, refs);
+                }
+                refs.pop();
+                include.childs = vec![v];
+            }
+            NodeType::NChoose(choose) => {
+                if let Some(when_nodes) = &mut choose.when_nodes {
+                    loop_replace_include_node(when_nodes, arg, refs);
+                }
+                if let Some(otherwise) = &mut choose.otherwise_node {
+                    if let Some(childs) = otherwise.childs_mut() {
+                        loop_replace_include_node(childs, arg, refs);
                     }
-                    include.childs = vec![v.take().unwrap()];
                 }
-                _ => {}
+            }
+            _ => {
+                if let Some(childs) = x.childs_mut() {
+                    loop_replace_include_node(childs, arg, refs);
+                }
             }
         }
     }
@@ -99,23 +120,6 @@ fn find_node(arg: &HashMap<String, NodeType>, id: &str) -> Option<NodeType> {
 }
 
 
-fn loop_find_include_node<'m>(m: &'m mut Vec<NodeType>, result: &mut Vec<&'m mut NodeType>) {
-    for x in m {
-        match x {
-            NodeType::NInclude(_) => {
-                result.push(x);
-            }
-            _ => {
-                let childs = x.childs_mut();
-                if childs.is_some() {
-                    return loop_find_include_node(childs.unwrap(), result);
-                }
-            }
-        }
-    }
-}
-
-
 pub fn loop_decode_xml(xml_vec: &Vec<Element>) -> Vec<NodeType> {
     let mut nodes = vec![];
     for xml in xml_vec {
@@ -134,6 +138,7 @@ pub fn loop_decode_xml(xml_vec: &Vec<Element>) -> Vec<NodeType> {
             }
             "select" => nodes.push(NodeType::NSelectNode(SelectNode {
                 id: xml.get_attr("id"),
+                result_map: xml.get_attr("result_map"),
                 childs: child_nodes,
             })),
             "update" => nodes.push(NodeType::NUpdateNode(UpdateNode {
@@ -201,11 +206,13 @@ pub fn loop_decode_xml(xml_vec: &Vec<Element>) -> Vec<NodeType> {
 
             "id" => nodes.push(NodeType::NResultMapIdNode(ResultMapIdNode {
                 column: xml.get_attr("column"),
+                property: xml.get_attr("property"),
                 lang_type: xml.get_attr("lang_type"),
             })),
 
             "result" => nodes.push(NodeType::NResultMapResultNode(ResultMapResultNode {
                 column: xml.get_attr("column"),
+                property: xml.get_attr("property"),
                 lang_type: xml.get_attr("lang_type"),
                 version_enable: xml.get_attr("version_enable"),
                 logic_enable: xml.get_attr("logic_enable"),
@@ -289,3 +296,63 @@ pub fn filter_otherwise_nodes(arg: Vec<NodeType>) -> Option<Box<NodeType>> {
 }
 
 
+#[test]
+pub fn test_include_sql() {
+    let m = parser(r#"
+<mapper>
+    <sql id="columns">id,name</sql>
+    <sql id="where_name">
+        <where>
+            <if test="name != null">name = #{name}</if>
+        </where>
+    </sql>
+    <sql id="all">select <include refid="columns"/> from biz_activity</sql>
+    <select id="select">
+        <include refid="all"/>
+        <if test="true">
+            <include refid="where_name"/>
+        </if>
+    </select>
+</mapper>"#);
+    let mut engine = RbatisEngine::new();
+    let mut env = serde_json::json!({"name": "a"});
+    let mut arg_array = vec![];
+    let r = crate::ast::ast::RbatisAST::eval(m.get("select").unwrap(), &rbatis_core::db::DriverType::Mysql, &mut env, &mut engine, &mut arg_array).unwrap();
+    println!("{}", r);
+    assert!(r.contains("id,name"));
+    assert!(r.contains("name = ?"));
+    assert_eq!(arg_array, vec![serde_json::json!("a")]);
+}
+
+#[test]
+#[should_panic]
+pub fn test_include_circular() {
+    parser(r#"
+<mapper>
+    <sql id="a"><include refid="b"/></sql>
+    <sql id="b"><include refid="a"/></sql>
+</mapper>"#);
+}
+
+#[test]
+pub fn test_result_map_property() {
+    let m = parser(r#"
+<mapper>
+    <result_map id="activity_map" table="biz_activity">
+        <id column="act_id" property="id"/>
+        <result column="c_name" property="customer.name"/>
+        <result column="version"/>
+    </result_map>
+    <select id="select" result_map="activity_map">select a.id as act_id,c.name as c_name,a.version from biz_activity a left join customer c on a.customer_id = c.id</select>
+</mapper>"#);
+    match m.get("select").unwrap() {
+        NodeType::NSelectNode(node) => assert_eq!(node.result_map, "activity_map"),
+        _ => panic!("not select"),
+    }
+    let result_map = match m.get("activity_map").unwrap() {
+        NodeType::NResultMapNode(node) => node.to_result_map(),
+        _ => panic!("not result_map"),
+    };
+    let row = result_map.map_row(serde_json::json!({"act_id": "1", "c_name": "a", "version": 1}));
+    assert_eq!(row, serde_json::json!({"id": "1", "customer": {"name": "a"}, "version": 1}));
+}
@@ -19,6 +19,12 @@ pub struct BindNode {
 impl RbatisAST for BindNode {
     fn eval(&self, convert: &impl StmtConvert, env: &mut Value, engine: &RbatisEngine, arg_array: &mut Vec<Value>) -> Result<String, rbatis_core::Error> {
         let r = engine.eval(self.value.as_str(), env)?;
+        if env.is_null() {
+            *env = json!({});
+        }
+        if !env.is_object() {
+            return Result::Err(rbatis_core::Error::from("[rbatis] bind name:'".to_owned() + self.name.as_str() + "' fail,the arg must be an json object!"));
+        }
         env[self.name.as_str()] = r;
         return Result::Ok("".to_string());
     }
@@ -52,4 +58,27 @@ fn test_bind_node() {
 
     println!("r={}", r);
     println!("john[a]={}", john["a"]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_bind_like_pattern() {
+    let mut engine = RbatisEngine::new();
+    let bind_node = BindNode {
+        name: "pattern".to_string(),
+        value: "'%' + name + '%'".to_string(),
+    };
+    let mut env = json!({
+        "name": "test",
+    });
+    let mut arg_array = vec![];
+    bind_node.eval(&DriverType::Mysql, &mut env, &mut engine, &mut arg_array).unwrap();
+    assert_eq!(env["pattern"], json!("%test%"));
+
+    let mut env = Value::Null;
+    let bind_node = BindNode {
+        name: "a".to_string(),
+        value: "1".to_string(),
+    };
+    bind_node.eval(&DriverType::Mysql, &mut env, &mut engine, &mut arg_array).unwrap();
+    assert_eq!(env["a"], json!(1));
+}
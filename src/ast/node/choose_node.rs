@@ -7,11 +7,12 @@ use rbatis_core::convert::StmtConvert;
 use rbatis_core::db::DriverType;
 
 use crate::ast::ast::RbatisAST;
-use crate::ast::node::node::{create_deep, print_child, SqlNodePrint};
+use crate::ast::node::node::{create_deep, do_child_nodes, print_child, SqlNodePrint};
 use crate::ast::node::node_type::NodeType;
 use crate::ast::node::node_type::NodeType::NString;
 use crate::ast::node::otherwise_node::OtherwiseNode;
 use crate::ast::node::string_node::StringNode;
+use crate::ast::node::when_node::WhenNode;
 use crate::engine::runtime::RbatisEngine;
 
 #[derive(Clone, Debug)]
@@ -21,17 +22,19 @@ pub struct ChooseNode {
 }
 
 impl RbatisAST for ChooseNode {
+    /// only the first matched when(or the otherwise if no when matched) is eval
     fn eval(&self, convert: &impl StmtConvert, env: &mut Value, engine: &RbatisEngine, arg_array: &mut Vec<Value>) -> Result<String, rbatis_core::Error> {
-        if self.when_nodes.is_none() == false {
-            for item in self.when_nodes.clone().unwrap() {
-                let s = item.eval(convert, env, engine, arg_array);
-                if s.is_ok() {
-                    return s;
+        if let Some(when_nodes) = &self.when_nodes {
+            for item in when_nodes {
+                if let NodeType::NWhen(when) = item {
+                    if when.is_match(env, engine)? {
+                        return do_child_nodes(convert, &when.childs, env, engine, arg_array);
+                    }
                 }
             }
         }
-        if self.otherwise_node.is_none() == false {
-            return self.otherwise_node.clone().unwrap().deref_mut().eval(convert, env, engine, arg_array);
+        if let Some(otherwise_node) = &self.otherwise_node {
+            return otherwise_node.eval(convert, env, engine, arg_array);
         }
         return Result::Ok("".to_string());
     }
@@ -40,8 +43,12 @@ impl RbatisAST for ChooseNode {
 impl SqlNodePrint for ChooseNode {
     fn print(&self, deep: i32) -> String {
         let mut result = create_deep(deep) + "<choose>";
-        result = result + print_child(self.when_nodes.as_ref().unwrap(), deep + 1).as_str();
-        result = result + self.otherwise_node.as_ref().unwrap().print(deep).as_str();
+        if let Some(when_nodes) = &self.when_nodes {
+            result = result + print_child(when_nodes, deep + 1).as_str();
+        }
+        if let Some(otherwise_node) = &self.otherwise_node {
+            result = result + otherwise_node.print(deep + 1).as_str();
+        }
         result = result + create_deep(deep).as_str() + "</choose>";
         return result;
     }
@@ -65,4 +72,23 @@ pub fn test_choose_node() {
 
     let r = c.eval(&DriverType::Mysql, &mut john, &mut engine, &mut arg_array);
     println!("{}", r.unwrap());
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_choose_when_otherwise() {
+    let mut engine = RbatisEngine::new();
+    let c = ChooseNode {
+        when_nodes: Option::Some(vec![
+            NodeType::NWhen(WhenNode { childs: vec![NString(StringNode::new("a > 1"))], test: "arg > 1".to_string() }),
+            NodeType::NWhen(WhenNode { childs: vec![NString(StringNode::new("a > 0"))], test: "arg > 0".to_string() }),
+        ]),
+        otherwise_node: Some(Box::new(NodeType::NOtherwise(OtherwiseNode { childs: vec![NString(StringNode::new("a <= 0"))] }))),
+    };
+    let mut arg_array = vec![];
+    let r = c.eval(&DriverType::Mysql, &mut json!({"arg": 2}), &mut engine, &mut arg_array).unwrap();
+    assert_eq!(r.trim(), "a > 1");
+    let r = c.eval(&DriverType::Mysql, &mut json!({"arg": 1}), &mut engine, &mut arg_array).unwrap();
+    assert_eq!(r.trim(), "a > 0");
+    let r = c.eval(&DriverType::Mysql, &mut json!({"arg": 0}), &mut engine, &mut arg_array).unwrap();
+    assert_eq!(r.trim(), "a <= 0");
+}
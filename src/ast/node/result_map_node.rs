@@ -13,6 +13,7 @@ use crate::ast::node::node_type::NodeType;
 use crate::ast::node::otherwise_node::OtherwiseNode;
 use crate::ast::node::result_map_id_node::ResultMapIdNode;
 use crate::ast::node::result_map_result_node::ResultMapResultNode;
+use crate::decode::result_map::ResultMap;
 use crate::engine::runtime::RbatisEngine;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -60,6 +61,22 @@ impl ResultMapNode {
     pub fn find_delete_flag(&self) -> &ResultMapResultNode {
         unimplemented!()
     }
+
+    /// the column => property map of the id and results(that set property)
+    pub fn to_result_map(&self) -> ResultMap {
+        let mut map = ResultMap::new();
+        if let Some(id_node) = &self.id_node {
+            if !id_node.property.is_empty() {
+                map = map.result(&id_node.column, &id_node.property);
+            }
+        }
+        for item in &self.results {
+            if !item.property.is_empty() {
+                map = map.result(&item.column, &item.property);
+            }
+        }
+        return map;
+    }
 }
 
 impl RbatisAST for ResultMapNode {
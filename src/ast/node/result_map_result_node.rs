@@ -13,6 +13,8 @@ use crate::engine::runtime::RbatisEngine;
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ResultMapResultNode {
     pub column: String,
+    /// the struct field(can be nested by '.'),empty is same as column
+    pub property: String,
     pub lang_type: String,
 
     pub version_enable: String,
@@ -32,6 +34,9 @@ impl SqlNodePrint for ResultMapResultNode {
     fn print(&self, deep: i32) -> String {
         let mut result = create_deep(deep) + "<result ";
         result = result + " column=\"" + self.column.as_str() + "\"";
+        if !self.property.is_empty() {
+            result = result + " property=\"" + self.property.as_str() + "\"";
+        }
         result = result + " lang_type=\"" + self.lang_type.as_str() + "\"";
         result = result + "></result>";
         return result;
@@ -10,6 +10,8 @@ use crate::engine::runtime::RbatisEngine;
 #[derive(Clone, Debug)]
 pub struct SelectNode {
     pub id: String,
+    /// id of the <result_map> to decode the rows,empty is not map
+    pub result_map: String,
     pub childs: Vec<NodeType>,
 }
 
@@ -24,6 +26,9 @@ impl SqlNodePrint for SelectNode {
     fn print(&self, deep: i32) -> String {
         let mut result = create_deep(deep) + "<select ";
         result = result + "id=\"" + self.id.as_str() + "\"";
+        if !self.result_map.is_empty() {
+            result = result + " result_map=\"" + self.result_map.as_str() + "\"";
+        }
         result = result + ">";
         result = result + print_child(self.childs.as_ref(), deep + 1).as_str();
         result = result + create_deep(deep).as_str() + "</select>";
@@ -19,14 +19,21 @@ pub struct WhenNode {
 
 impl RbatisAST for WhenNode {
     fn eval(&self, convert: &impl StmtConvert, env: &mut Value, engine: &RbatisEngine, arg_array: &mut Vec<Value>) -> Result<String, rbatis_core::Error> {
+        if self.is_match(env, engine)? {
+            return do_child_nodes(convert, &self.childs, env, engine, arg_array);
+        }
+        return Result::Ok("".to_string());
+    }
+}
+
+impl WhenNode {
+    /// eval the test
+    pub fn is_match(&self, env: &mut Value, engine: &RbatisEngine) -> Result<bool, rbatis_core::Error> {
         let result = engine.eval(self.test.as_str(), env)?;
         if !result.is_boolean() {
             return Result::Err(rbatis_core::Error::from("[rbatis] test:'".to_owned() + self.test.as_str() + "' is not return bool!"));
         }
-        if result.as_bool().unwrap() {
-            return do_child_nodes(convert, &self.childs, env, engine, arg_array);
-        }
-        return Result::Ok("".to_string());
+        return Ok(result.as_bool().unwrap());
     }
 }
 
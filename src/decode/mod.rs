@@ -1 +1,2 @@
-pub mod decoder;
\ No newline at end of file
+pub mod decoder;
+pub mod result_map;
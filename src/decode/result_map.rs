@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+/// map the columns(or alias) of rows to the struct fields,the field can be nested by '.',for example:
+///   let map = ResultMap::new().result("act_name", "name").result("c_id", "customer.id").result("c_name", "customer.name");
+///   let r: Vec<Activity> = rb.fetch_prepare_map("", "select a.name as act_name,c.id as c_id,c.name as c_name from ...", &vec![], &map).await?;
+/// the unmapped columns keep the column name,
+/// the nested object is null if all the fields are null(for example the left join not match)
+#[derive(Clone, Debug, Default)]
+pub struct ResultMap {
+    /// column => property path
+    pub columns: HashMap<String, Vec<String>>,
+}
+
+impl ResultMap {
+    pub fn new() -> Self {
+        Self {
+            columns: HashMap::new(),
+        }
+    }
+
+    /// map the column to the property(for example "customer.name")
+    pub fn result(mut self, column: &str, property: &str) -> Self {
+        self.columns.insert(column.to_string(), property.split('.').map(|x| x.to_string()).collect());
+        self
+    }
+
+    pub fn map_rows(&self, rows: Vec<Value>) -> Vec<Value> {
+        rows.into_iter().map(|x| self.map_row(x)).collect()
+    }
+
+    pub fn map_row(&self, row: Value) -> Value {
+        let row = match row {
+            Value::Object(row) => row,
+            _ => return row,
+        };
+        let mut result = Map::new();
+        let mut nested: Vec<Vec<String>> = vec![];
+        for (column, value) in row {
+            let path = match self.columns.get(&column) {
+                Some(path) => path,
+                None => {
+                    result.insert(column, value);
+                    continue;
+                }
+            };
+            let mut obj = &mut result;
+            for (index, name) in path.iter().enumerate() {
+                if index + 1 == path.len() {
+                    obj.insert(name.clone(), value);
+                    break;
+                }
+                let parent = path[..index + 1].to_vec();
+                if !nested.contains(&parent) {
+                    nested.push(parent);
+                }
+                let child = obj.entry(name.clone()).or_insert_with(|| Value::Object(Map::new()));
+                if !child.is_object() {
+                    *child = Value::Object(Map::new());
+                }
+                obj = child.as_object_mut().unwrap();
+            }
+        }
+        //the deepest first,so an object of null objects is null
+        nested.sort_by(|a, b| b.len().cmp(&a.len()));
+        for path in nested {
+            let mut obj = Some(&mut result);
+            for name in &path[..path.len() - 1] {
+                obj = obj.and_then(|x| x.get_mut(name)).and_then(|x| x.as_object_mut());
+            }
+            if let Some(obj) = obj {
+                let last = &path[path.len() - 1];
+                let all_null = obj.get(last).and_then(|x| x.as_object()).map(|x| x.values().all(|v| v.is_null())).unwrap_or(false);
+                if all_null {
+                    obj.insert(last.clone(), Value::Null);
+                }
+            }
+        }
+        Value::Object(result)
+    }
+}
+
+mod test {
+    use serde_json::json;
+
+    use crate::decode::result_map::ResultMap;
+
+    #[test]
+    fn test_map_row() {
+        let map = ResultMap::new()
+            .result("act_name", "name")
+            .result("c_id", "customer.id")
+            .result("c_name", "customer.name")
+            .result("c_addr_city", "customer.address.city");
+        let rows = map.map_rows(vec![
+            json!({"id": "1", "act_name": "a", "c_id": "2", "c_name": "b", "c_addr_city": null}),
+            json!({"id": "3", "act_name": "c", "c_id": null, "c_name": null, "c_addr_city": null}),
+        ]);
+        assert_eq!(rows[0], json!({"id": "1", "name": "a", "customer": {"id": "2", "name": "b", "address": null}}));
+        assert_eq!(rows[1], json!({"id": "3", "name": "c", "customer": null}));
+    }
+}
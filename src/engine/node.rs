@@ -9,8 +9,8 @@ use serde_json::json;
 use serde_json::value::Value::{Null, Number};
 
 use crate::engine::eval::eval;
-use crate::engine::node::NodeType::{NArg, NBinary, NBool, NNull, NNumber, NOpt, NString};
-use crate::engine::runtime::{is_number, OptMap, parser_tokens};
+use crate::engine::node::NodeType::{NArg, NBinary, NBool, NFn, NNull, NNumber, NOpt, NString};
+use crate::engine::runtime::{call_fn, is_number, OptMap, parser_tokens};
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum NodeType {
@@ -27,6 +27,7 @@ pub enum NodeType {
     NBinary = 6,
     //二元计算节点
     NOpt = 7,           //操作符节点
+    NFn = 8,            //函数调用节点
 }
 
 impl Display for NodeType {
@@ -39,6 +40,7 @@ impl Display for NodeType {
             NNull => f.write_str("NNull"),
             NBinary => f.write_str("NBinary"),
             NOpt => f.write_str("NOpt"),
+            NFn => f.write_str("NFn"),
         }
     }
 }
@@ -51,6 +53,8 @@ pub struct Node {
     pub left_binary_node: Option<Box<Node>>,
     pub right_binary_node: Option<Box<Node>>,
     pub node_type: NodeType,
+    //函数调用节点的参数
+    pub fn_args: Vec<Node>,
 }
 
 impl Node {
@@ -110,6 +114,12 @@ impl Node {
                 index = index + 1;
             }
             return Result::Ok(Value::Null);
+        } else if self.equal_node_type(&NFn) {
+            let mut args = vec![];
+            for item in &self.fn_args {
+                args.push(item.eval(env)?);
+            }
+            return call_fn(self.to_string(), &args);
         }
         return Result::Ok(self.value.clone());
     }
@@ -125,6 +135,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NNull,
+            fn_args: vec![],
         }
     }
     pub fn new_arg(arg: &str) -> Self {
@@ -134,6 +145,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NArg,
+            fn_args: vec![],
         }
     }
     pub fn new_string(arg: &str) -> Self {
@@ -142,6 +154,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NString,
+            fn_args: vec![],
         }
     }
     pub fn new_number_f64(arg: f64) -> Self {
@@ -150,6 +163,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NNumber,
+            fn_args: vec![],
         }
     }
     pub fn new_number_i64(arg: i64) -> Self {
@@ -158,6 +172,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NNumber,
+            fn_args: vec![],
         }
     }
     pub fn new_number_u64(arg: u64) -> Self {
@@ -166,6 +181,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NNumber,
+            fn_args: vec![],
         }
     }
 
@@ -175,6 +191,7 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NBool,
+            fn_args: vec![],
         }
     }
     pub fn new_binary(arg_lef: Box<Node>, arg_right: Box<Node>, opt: &str) -> Self {
@@ -183,6 +200,7 @@ impl Node {
             left_binary_node: Option::Some(arg_lef),
             right_binary_node: Option::Some(arg_right),
             node_type: NBinary,
+            fn_args: vec![],
         }
     }
     pub fn new_opt(arg: &str) -> Self {
@@ -191,6 +209,17 @@ impl Node {
             left_binary_node: None,
             right_binary_node: None,
             node_type: NOpt,
+            fn_args: vec![],
+        }
+    }
+
+    pub fn new_fn(name: &str, args: Vec<Node>) -> Self {
+        Self {
+            value: Value::String(name.to_string()),
+            left_binary_node: None,
+            right_binary_node: None,
+            node_type: NFn,
+            fn_args: args,
         }
     }
 
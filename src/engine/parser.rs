@@ -8,9 +8,14 @@ use crate::engine::node::NodeType::{NBinary, NOpt};
 use crate::engine::runtime::{is_number, OptMap, parser_tokens};
 
 pub fn parser(express: String, opt_map: &OptMap) -> Result<Node, rbatis_core::Error> {
+    let (express, fn_nodes) = parser_fns(&express, opt_map)?;
     let tokens = parser_tokens(&express, opt_map);
     let mut nodes = vec![];
     for item in tokens {
+        if let Some(node) = fn_nodes.get(&item) {
+            nodes.push(Box::new(node.clone()));
+            continue;
+        }
         let node = Node::parser(item.as_str(), opt_map);
         if node.node_type == NOpt {
             let is_allow_opt = opt_map.is_allow_opt(item.as_str());
@@ -65,4 +70,85 @@ fn have_opt(node_arg: &mut Vec<Box<Node>>) -> bool {
         }
     }
     return false;
-}
\ No newline at end of file
+}
+
+///replace the fn calls(for example: fn_trim(name)) with placeholder args,return the fn nodes of the placeholders
+fn parser_fns(express: &str, opt_map: &OptMap) -> Result<(String, HashMap<String, Node>), rbatis_core::Error> {
+    let mut fn_nodes = HashMap::new();
+    if !express.contains('(') {
+        return Ok((express.to_string(), fn_nodes));
+    }
+    let chars: Vec<char> = express.chars().collect();
+    let mut result = String::new();
+    let mut quote: Option<char> = None;
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        index += 1;
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            result.push(c);
+            continue;
+        }
+        if c == '\'' || c == '`' {
+            quote = Some(c);
+            result.push(c);
+            continue;
+        }
+        if c != '(' {
+            result.push(c);
+            continue;
+        }
+        let name_len = result.chars().rev().take_while(|x| x.is_ascii_alphanumeric() || *x == '_').count();
+        if name_len == 0 {
+            return Err(rbatis_core::Error::from(format!("[rbatis] parser express fail,'(' must after an fn name: {}", express)));
+        }
+        let name = result[result.len() - name_len..].to_string();
+        result.truncate(result.len() - name_len);
+        //find the ')' and split the args by ','
+        let mut args = vec![];
+        let mut arg = String::new();
+        let mut depth = 0;
+        let mut closed = false;
+        while index < chars.len() {
+            let c = chars[index];
+            index += 1;
+            if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                }
+            } else if c == '\'' || c == '`' {
+                quote = Some(c);
+            } else if c == '(' {
+                depth += 1;
+            } else if c == ')' {
+                if depth == 0 {
+                    closed = true;
+                    break;
+                }
+                depth -= 1;
+            } else if c == ',' && depth == 0 {
+                args.push(arg.clone());
+                arg.clear();
+                continue;
+            }
+            arg.push(c);
+        }
+        if !closed {
+            return Err(rbatis_core::Error::from(format!("[rbatis] parser express fail,'(' not closed: {}", express)));
+        }
+        if !arg.trim().is_empty() || !args.is_empty() {
+            args.push(arg);
+        }
+        let mut arg_nodes = vec![];
+        for arg in args {
+            arg_nodes.push(parser(arg.trim().to_string(), opt_map)?);
+        }
+        let placeholder = format!("__rbatis_fn_{}", fn_nodes.len());
+        result.push_str(&placeholder);
+        fn_nodes.insert(placeholder, Node::new_fn(&name, arg_nodes));
+    }
+    Ok((result, fn_nodes))
+}
@@ -74,4 +74,25 @@ fn test_benchmark() {
 //    b.iter(|| {
 //        parser::parser(String::from(" a + b"), m);
 //    });
-//}
\ No newline at end of file
+//}
+#[test]
+fn test_parser_fn() {
+    runtime::RbatisEngine::register_fn("fn_trim", |args| Ok(json!(args[0].as_str().unwrap_or_default().trim())));
+    runtime::RbatisEngine::register_fn("fn_concat", |args| {
+        let mut s = String::new();
+        for x in args {
+            s.push_str(x.as_str().unwrap_or_default());
+        }
+        Ok(json!(s))
+    });
+    let john = json!({
+        "name": " John ",
+    });
+    let node = parser::parser(String::from("fn_trim(name) == 'John'"), &OptMap::new()).unwrap();
+    assert_eq!(node.eval(&john).unwrap(), json!(true));
+    let node = parser::parser(String::from("fn_concat('%', fn_trim(name), '%')"), &OptMap::new()).unwrap();
+    assert_eq!(node.eval(&john).unwrap(), json!("%John%"));
+    let node = parser::parser(String::from("fn_not_exist(name)"), &OptMap::new()).unwrap();
+    assert!(node.eval(&john).is_err());
+    assert!(parser::parser(String::from("fn_trim(name"), &OptMap::new()).is_err());
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::collections::linked_list::LinkedList;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use serde_json::Value;
 
@@ -11,8 +11,13 @@ lazy_static! {
    /// for engine: if cache not have expr value,it will be redo parser code.not wait cache return for no blocking
    /// global expr cache,use RwLock but not blocking
    static ref  EXPR_CACHE: RwLock<HashMap<String, Node>> = RwLock::new(HashMap::new());
+   /// global user defined fns
+   static ref  FN_MAP: RwLock<HashMap<String, Arc<EngineFn>>> = RwLock::new(HashMap::new());
 }
 
+/// user defined fn,args are the evaluated values
+pub type EngineFn = dyn Fn(&[Value]) -> Result<Value, rbatis_core::Error> + Send + Sync;
+
 /// the express engine for  exe code on runtime
 #[derive(Clone, Debug)]
 pub struct RbatisEngine {
@@ -26,6 +31,15 @@ impl RbatisEngine {
         };
     }
 
+    /// register an fn callable in py sql/xml express(global),for example:
+    ///   RbatisEngine::register_fn("fn_trim", |args| Ok(json!(args[0].as_str().unwrap_or_default().trim())));
+    ///   if fn_trim(name) != '':
+    ///     AND name = #{fn_trim(name)}
+    pub fn register_fn<F>(name: &str, f: F)
+        where F: Fn(&[Value]) -> Result<Value, rbatis_core::Error> + Send + Sync + 'static {
+        FN_MAP.write().unwrap().insert(name.to_string(), Arc::new(f));
+    }
+
     ///eval express with arg value,if cache have value it will no run parser expr.
     pub fn eval(&self, expr: &str, arg: &Value) -> Result<Value, rbatis_core::Error> {
         let mut lexer_arg = expr.to_string();
@@ -84,6 +98,14 @@ impl RbatisEngine {
     }
 }
 
+/// call the registered fn
+pub fn call_fn(name: &str, args: &[Value]) -> Result<Value, rbatis_core::Error> {
+    let f = FN_MAP.read().unwrap().get(name).cloned();
+    match f {
+        Some(f) => f(args),
+        None => Result::Err(rbatis_core::Error::from(format!("[rbatis] fn '{}' not registered!", name))),
+    }
+}
 
 pub fn is_number(arg: &String) -> bool {
     let chars = arg.chars();
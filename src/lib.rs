@@ -20,4 +20,7 @@ pub mod plugin;
 pub mod rbatis;
 pub mod sql;
 pub mod crud;
-pub mod wrapper;
\ No newline at end of file
+pub mod wrapper;
+pub mod tx;
+pub mod lazy;
+pub mod proc;
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use rbatis_core::convert::StmtConvert;
+use rbatis_core::db::DriverType;
+use rbatis_core::Result;
+
+use crate::plugin::subscribe::TableOp;
+
+/// one data change captured by CRUD INSERT/UPDATE/DELETE
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AuditRecord {
+    pub table: String,
+    pub op: TableOp,
+    /// changed ids,empty if unknown(for example update_by_wrapper)
+    pub ids: Vec<Value>,
+    /// rows before update/delete,None if not captured
+    pub before: Option<Value>,
+    /// values of insert/update,None for delete
+    pub after: Option<Value>,
+    pub operator: Option<Value>,
+    pub time: String,
+}
+
+impl AuditRecord {
+    pub fn new(audit: &dyn Audit, table: &str, op: TableOp, ids: Vec<Value>, before: Option<Value>, after: Option<Value>) -> Self {
+        Self {
+            table: table.to_string(),
+            op,
+            ids,
+            before,
+            after,
+            operator: audit.operator(),
+            time: chrono::Local::now().format(audit.time_format()).to_string(),
+        }
+    }
+}
+
+/// Audit Plugin trait
+pub trait Audit: Send + Sync {
+    /// the table changes need audit
+    fn is_audit_table(&self, table_name: &str) -> bool;
+    /// select the rows before update/delete(costs one more query)
+    fn capture_before(&self) -> bool;
+    /// current operator,for example current user of an task local
+    fn operator(&self) -> Option<Value>;
+    /// chrono format of the record time
+    fn time_format(&self) -> &str;
+    /// Some(table) write the records into this table in the same tx_id of the change
+    fn audit_table(&self) -> Option<&str>;
+    /// called with every record
+    fn on_audit(&self, record: &AuditRecord);
+
+    /// make the insert sql of audit table,
+    /// columns: table_name,op,ids,before_value,after_value,operator,create_time
+    fn make_insert_sql(&self, driver_type: &DriverType, audit_table: &str, record: &AuditRecord) -> Result<(String, Vec<Value>)> {
+        let mut values = vec![];
+        for i in 0..7 {
+            values.push(driver_type.stmt_convert(i));
+        }
+        let sql = format!("INSERT INTO {} (table_name,op,ids,before_value,after_value,operator,create_time) VALUES ({})", audit_table, values.join(","));
+        let to_json = |v: &Option<Value>| -> Value {
+            match v {
+                Some(v) => Value::String(v.to_string()),
+                None => Value::Null,
+            }
+        };
+        let args = vec![
+            Value::String(record.table.clone()),
+            Value::String(format!("{:?}", record.op)),
+            Value::String(Value::Array(record.ids.clone()).to_string()),
+            to_json(&record.before),
+            to_json(&record.after),
+            record.operator.clone().unwrap_or(Value::Null),
+            Value::String(record.time.clone()),
+        ];
+        Ok((sql, args))
+    }
+}
+
+/// audit every table(except the ignore tables),records are written into audit_table and/or sent to the callback
+pub struct RbatisAuditPlugin {
+    pub audit_table: Option<String>,
+    pub capture_before: bool,
+    pub ignore_tables: HashSet<String>,
+    /// chrono format of the time
+    pub time_format: String,
+    pub operator: Option<Arc<dyn Fn() -> Option<Value> + Send + Sync>>,
+    pub callback: Option<Arc<dyn Fn(&AuditRecord) + Send + Sync>>,
+}
+
+impl RbatisAuditPlugin {
+    pub fn new() -> Self {
+        Self {
+            audit_table: None,
+            capture_before: false,
+            ignore_tables: HashSet::new(),
+            time_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            operator: None,
+            callback: None,
+        }
+    }
+
+    pub fn audit_table(mut self, table_name: &str) -> Self {
+        self.audit_table = Some(table_name.to_string());
+        self
+    }
+
+    pub fn capture_before(mut self, capture_before: bool) -> Self {
+        self.capture_before = capture_before;
+        self
+    }
+
+    pub fn ignore_table(mut self, table_name: &str) -> Self {
+        self.ignore_tables.insert(table_name.to_string());
+        self
+    }
+
+    pub fn set_operator<F>(mut self, f: F) -> Self
+        where F: Fn() -> Option<Value> + Send + Sync + 'static {
+        self.operator = Some(Arc::new(f));
+        self
+    }
+
+    pub fn set_callback<F>(mut self, f: F) -> Self
+        where F: Fn(&AuditRecord) + Send + Sync + 'static {
+        self.callback = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Default for RbatisAuditPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Audit for RbatisAuditPlugin {
+    fn is_audit_table(&self, table_name: &str) -> bool {
+        if let Some(audit_table) = &self.audit_table {
+            if audit_table == table_name {
+                return false;
+            }
+        }
+        !self.ignore_tables.contains(table_name)
+    }
+
+    fn capture_before(&self) -> bool {
+        self.capture_before
+    }
+
+    fn operator(&self) -> Option<Value> {
+        self.operator.as_ref().and_then(|f| f())
+    }
+
+    fn time_format(&self) -> &str {
+        self.time_format.as_str()
+    }
+
+    fn audit_table(&self) -> Option<&str> {
+        self.audit_table.as_ref().map(|x| x.as_str())
+    }
+
+    fn on_audit(&self, record: &AuditRecord) {
+        if let Some(callback) = &self.callback {
+            callback(record);
+        }
+    }
+}
+
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use rbatis_core::db::DriverType;
+
+    use crate::plugin::audit::{Audit, AuditRecord, RbatisAuditPlugin};
+    use crate::plugin::subscribe::TableOp;
+
+    #[test]
+    fn test_audit_record() {
+        let records = Arc::new(Mutex::new(vec![]));
+        let r = records.clone();
+        let plugin = RbatisAuditPlugin::new()
+            .audit_table("sys_audit_log")
+            .ignore_table("sys_log")
+            .set_operator(|| Some(json!("admin")))
+            .set_callback(move |x| r.lock().unwrap().push(x.clone()));
+        assert!(plugin.is_audit_table("biz_activity"));
+        assert!(!plugin.is_audit_table("sys_log"));
+        assert!(!plugin.is_audit_table("sys_audit_log"));
+
+        let record = AuditRecord::new(&plugin, "biz_activity", TableOp::Update, vec![json!("1")], Some(json!([{"id": "1", "name": "a"}])), Some(json!({"id": "1", "name": "b"})));
+        plugin.on_audit(&record);
+        assert_eq!(records.lock().unwrap().len(), 1);
+        assert_eq!(records.lock().unwrap()[0].operator, Some(json!("admin")));
+
+        let (sql, args) = plugin.make_insert_sql(&DriverType::Mysql, "sys_audit_log", &record).unwrap();
+        println!("{}", sql);
+        assert_eq!(sql, "INSERT INTO sys_audit_log (table_name,op,ids,before_value,after_value,operator,create_time) VALUES ( ? , ? , ? , ? , ? , ? , ? )");
+        assert_eq!(args[1], json!("Update"));
+        assert_eq!(args[2], json!("[\"1\"]"));
+        assert_eq!(args[4], json!("{\"id\":\"1\",\"name\":\"b\"}"));
+    }
+}
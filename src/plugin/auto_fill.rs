@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::crud::CRUDOp;
+
+/// Auto Fill Plugin trait,fill the field value map of an entity before insert/update
+pub trait AutoFill: Send + Sync {
+    /// op is Insert or Update,map is the field value map of the entity(the keys are the columns)
+    fn fill(&self, op: &CRUDOp, table_name: &str, map: &mut Map<String, Value>);
+}
+
+/// fill the audit columns(only the columns of the entity):
+/// on insert,the null create_time,update_time,create_by,update_by are filled.
+/// on update,the update_time,update_by are always set.
+/// the create_by/update_by value is from the operator callback,for example current user of an task local
+pub struct RbatisAutoFillPlugin {
+    pub create_time: String,
+    pub update_time: String,
+    pub create_by: String,
+    pub update_by: String,
+    /// chrono format of the time columns
+    pub time_format: String,
+    /// return the operator,None skip fill create_by/update_by
+    pub operator: Option<Arc<dyn Fn() -> Option<Value> + Send + Sync>>,
+}
+
+impl RbatisAutoFillPlugin {
+    pub fn new() -> Self {
+        Self {
+            create_time: "create_time".to_string(),
+            update_time: "update_time".to_string(),
+            create_by: "create_by".to_string(),
+            update_by: "update_by".to_string(),
+            time_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            operator: None,
+        }
+    }
+
+    pub fn set_operator<F>(mut self, f: F) -> Self
+        where F: Fn() -> Option<Value> + Send + Sync + 'static {
+        self.operator = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Default for RbatisAutoFillPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoFill for RbatisAutoFillPlugin {
+    fn fill(&self, op: &CRUDOp, table_name: &str, map: &mut Map<String, Value>) {
+        let now = Value::String(chrono::Local::now().format(&self.time_format).to_string());
+        let operator = self.operator.as_ref().and_then(|f| f());
+        let mut set = |column: &str, value: &Option<Value>, overwrite: bool| {
+            if let (Some(old), Some(value)) = (map.get_mut(column), value) {
+                if overwrite || old.is_null() {
+                    *old = value.clone();
+                }
+            }
+        };
+        let now = Some(now);
+        match op {
+            CRUDOp::Insert => {
+                set(&self.create_time, &now, false);
+                set(&self.update_time, &now, false);
+                set(&self.create_by, &operator, false);
+                set(&self.update_by, &operator, false);
+            }
+            CRUDOp::Update => {
+                set(&self.update_time, &now, true);
+                set(&self.update_by, &operator, true);
+            }
+            _ => {}
+        }
+    }
+}
+
+mod test {
+    use serde_json::{Map, Value};
+
+    use crate::crud::CRUDOp;
+    use crate::plugin::auto_fill::{AutoFill, RbatisAutoFillPlugin};
+
+    #[test]
+    fn test_auto_fill() {
+        let plugin = RbatisAutoFillPlugin::new().set_operator(|| Some(json!("admin")));
+        let mut map: Map<String, Value> = serde_json::from_value(json!({"id":"1","create_time":null,"update_time":"2020-01-01 00:00:00","create_by":null})).unwrap();
+        plugin.fill(&CRUDOp::Insert, "biz_activity", &mut map);
+        assert!(!map["create_time"].is_null());
+        assert_eq!(map["update_time"], json!("2020-01-01 00:00:00"));
+        assert_eq!(map["create_by"], json!("admin"));
+        assert!(!map.contains_key("update_by"));
+        plugin.fill(&CRUDOp::Update, "biz_activity", &mut map);
+        assert_ne!(map["update_time"], json!("2020-01-01 00:00:00"));
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use rbatis_core::Result;
+
+/// Second level cache Plugin trait of CRUD reads(fetch_by_id,fetch_by_wrapper,list_*),
+/// the key is the query signature(sql and args) in the table,
+/// all keys of the table are invalidated after the table is written by CRUD(save/update/remove).
+/// only the reads out of tx(tx_id is empty) and without joins use the cache,the writes in tx invalidate the table after commit
+#[async_trait]
+pub trait CachePlugin: Send + Sync {
+    /// the table reads can be cached
+    fn is_cache_table(&self, _table_name: &str) -> bool {
+        true
+    }
+    /// get the cached rows
+    async fn get(&self, table_name: &str, key: &str) -> Result<Option<Value>>;
+    /// put the rows
+    async fn put(&self, table_name: &str, key: &str, value: &Value) -> Result<()>;
+    /// invalidate all keys of the table
+    async fn invalidate(&self, table_name: &str) -> Result<()>;
+}
+
+/// make the cache key of an query
+pub fn make_cache_key(sql: &str, args: &[Value]) -> String {
+    format!("{}:{}", sql.trim(), serde_json::to_string(args).unwrap_or_default())
+}
+
+/// In-process LRU + TTL cache,for example dashboards hammer identical queries:
+///   rb.cache_plugin = Some(Arc::new(MemoryCachePlugin::new(1000, Duration::from_secs(60))));
+/// the least recently used entry is evicted if the cache is full
+pub struct MemoryCachePlugin {
+    pub capacity: usize,
+    pub ttl: Duration,
+    inner: Mutex<MemoryCache>,
+}
+
+struct MemoryCache {
+    tick: u64,
+    len: usize,
+    // map<table_name,map<key,(value,expire_at,last_used)>>
+    tables: HashMap<String, HashMap<String, (Value, Instant, u64)>>,
+}
+
+impl MemoryCachePlugin {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            inner: Mutex::new(MemoryCache {
+                tick: 0,
+                len: 0,
+                tables: HashMap::new(),
+            }),
+        }
+    }
+
+    /// count of the cached entries(include the expired but not removed)
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl MemoryCache {
+    fn evict_lru(&mut self) {
+        let mut lru: Option<(String, String, u64)> = None;
+        for (table, keys) in &self.tables {
+            for (key, (_, _, used)) in keys {
+                if lru.as_ref().map(|(_, _, x)| used < x).unwrap_or(true) {
+                    lru = Some((table.clone(), key.clone(), *used));
+                }
+            }
+        }
+        if let Some((table, key, _)) = lru {
+            self.remove(&table, &key);
+        }
+    }
+
+    fn remove(&mut self, table_name: &str, key: &str) {
+        if let Some(keys) = self.tables.get_mut(table_name) {
+            if keys.remove(key).is_some() {
+                self.len -= 1;
+            }
+            if keys.is_empty() {
+                self.tables.remove(table_name);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CachePlugin for MemoryCachePlugin {
+    async fn get(&self, table_name: &str, key: &str) -> Result<Option<Value>> {
+        let mut cache = self.inner.lock().unwrap();
+        cache.tick += 1;
+        let tick = cache.tick;
+        let mut expired = false;
+        let mut result = None;
+        if let Some((value, expire_at, used)) = cache.tables.get_mut(table_name).and_then(|x| x.get_mut(key)) {
+            if *expire_at <= Instant::now() {
+                expired = true;
+            } else {
+                *used = tick;
+                result = Some(value.clone());
+            }
+        }
+        if expired {
+            cache.remove(table_name, key);
+        }
+        Ok(result)
+    }
+
+    async fn put(&self, table_name: &str, key: &str, value: &Value) -> Result<()> {
+        let mut cache = self.inner.lock().unwrap();
+        cache.remove(table_name, key);
+        if cache.len >= self.capacity {
+            cache.evict_lru();
+        }
+        cache.tick += 1;
+        let tick = cache.tick;
+        cache.tables.entry(table_name.to_string()).or_insert_with(HashMap::new)
+            .insert(key.to_string(), (value.clone(), Instant::now() + self.ttl, tick));
+        cache.len += 1;
+        Ok(())
+    }
+
+    async fn invalidate(&self, table_name: &str) -> Result<()> {
+        let mut cache = self.inner.lock().unwrap();
+        if let Some(keys) = cache.tables.remove(table_name) {
+            cache.len -= keys.len();
+        }
+        Ok(())
+    }
+}
+
+/// Redis cache,every entry is an redis key with it's own ttl(key: prefix + table name + table version + query signature),
+/// invalidate an table is one INCR of the table version(key: prefix + "version:" + table name),the entries of the old version expire by ttl.
+/// all calls share one multiplexed connection
+#[cfg(feature = "redis-cache")]
+pub struct RedisCachePlugin {
+    pub client: redis::Client,
+    /// prefix of the redis keys,default is "rbatis:cache:"
+    pub prefix: String,
+    /// expire seconds of an entry,default is 600,0 is never expire(not recommended,the entries of the old versions are never removed)
+    pub ttl_secs: usize,
+    conn: Mutex<Option<redis::aio::MultiplexedConnection>>,
+}
+
+#[cfg(feature = "redis-cache")]
+impl RedisCachePlugin {
+    /// for example: RedisCachePlugin::new("redis://127.0.0.1:6379/")
+    pub fn new(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url).map_err(|e| rbatis_core::Error::from(format!("[rbatis] redis cache open fail: {}", e)))?;
+        Ok(Self {
+            client,
+            prefix: "rbatis:cache:".to_string(),
+            ttl_secs: 600,
+            conn: Mutex::new(None),
+        })
+    }
+
+    pub fn set_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    pub fn set_ttl_secs(mut self, ttl_secs: usize) -> Self {
+        self.ttl_secs = ttl_secs;
+        self
+    }
+
+    fn version_key(&self, table_name: &str) -> String {
+        format!("{}version:{}", self.prefix, table_name)
+    }
+
+    fn entry_key(&self, table_name: &str, version: u64, key: &str) -> String {
+        format!("{}{}:{}:{}", self.prefix, table_name, version, key)
+    }
+
+    /// the shared multiplexed connection,connect at the first call(or after an fail)
+    async fn conn(&self) -> Result<redis::aio::MultiplexedConnection> {
+        if let Some(conn) = self.conn.lock().unwrap().as_ref() {
+            return Ok(conn.clone());
+        }
+        let conn = self.client.get_multiplexed_async_std_connection().await
+            .map_err(|e| rbatis_core::Error::from(format!("[rbatis] redis cache connect fail: {}", e)))?;
+        *self.conn.lock().unwrap() = Some(conn.clone());
+        Ok(conn)
+    }
+
+    /// map the redis error,and drop the connection if it is broken
+    fn redis_err(&self, e: redis::RedisError) -> rbatis_core::Error {
+        if e.is_io_error() || e.is_connection_dropped() {
+            *self.conn.lock().unwrap() = None;
+        }
+        rbatis_core::Error::from(format!("[rbatis] redis cache fail: {}", e))
+    }
+
+    async fn version(&self, conn: &mut redis::aio::MultiplexedConnection, table_name: &str) -> Result<u64> {
+        let version: Option<u64> = redis::cmd("GET").arg(self.version_key(table_name)).query_async(conn).await.map_err(|e| self.redis_err(e))?;
+        Ok(version.unwrap_or(0))
+    }
+}
+
+#[cfg(feature = "redis-cache")]
+#[async_trait]
+impl CachePlugin for RedisCachePlugin {
+    async fn get(&self, table_name: &str, key: &str) -> Result<Option<Value>> {
+        let mut conn = self.conn().await?;
+        let version = self.version(&mut conn, table_name).await?;
+        let data: Option<String> = redis::cmd("GET").arg(self.entry_key(table_name, version, key)).query_async(&mut conn).await.map_err(|e| self.redis_err(e))?;
+        match data {
+            Some(data) => Ok(serde_json::from_str(&data).ok()),
+            None => Ok(None),
+        }
+    }
+
+    async fn put(&self, table_name: &str, key: &str, value: &Value) -> Result<()> {
+        let mut conn = self.conn().await?;
+        let version = self.version(&mut conn, table_name).await?;
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(self.entry_key(table_name, version, key)).arg(value.to_string());
+        if self.ttl_secs > 0 {
+            cmd.arg("EX").arg(self.ttl_secs);
+        }
+        cmd.query_async::<_, ()>(&mut conn).await.map_err(|e| self.redis_err(e))
+    }
+
+    async fn invalidate(&self, table_name: &str) -> Result<()> {
+        let mut conn = self.conn().await?;
+        redis::cmd("INCR").arg(self.version_key(table_name)).query_async::<_, ()>(&mut conn).await.map_err(|e| self.redis_err(e))
+    }
+}
+
+mod test {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::plugin::cache::{CachePlugin, make_cache_key, MemoryCachePlugin};
+
+    #[test]
+    fn test_make_cache_key() {
+        let key = make_cache_key(" SELECT * FROM biz_activity WHERE id =  ? ", &[json!("1")]);
+        assert_eq!(key, "SELECT * FROM biz_activity WHERE id =  ?:[\"1\"]");
+    }
+
+    #[test]
+    fn test_memory_cache() {
+        async_std::task::block_on(async {
+            let cache = MemoryCachePlugin::new(2, Duration::from_secs(60));
+            cache.put("biz_activity", "a", &json!([1])).await.unwrap();
+            cache.put("biz_activity", "b", &json!([2])).await.unwrap();
+            //a is used,so b is the lru
+            assert_eq!(cache.get("biz_activity", "a").await.unwrap(), Some(json!([1])));
+            cache.put("other", "c", &json!([3])).await.unwrap();
+            assert_eq!(cache.get("biz_activity", "b").await.unwrap(), None);
+            assert_eq!(cache.len(), 2);
+
+            cache.invalidate("biz_activity").await.unwrap();
+            assert_eq!(cache.get("biz_activity", "a").await.unwrap(), None);
+            assert_eq!(cache.get("other", "c").await.unwrap(), Some(json!([3])));
+            assert_eq!(cache.len(), 1);
+
+            let cache = MemoryCachePlugin::new(10, Duration::from_millis(0));
+            cache.put("biz_activity", "a", &json!([1])).await.unwrap();
+            //expired
+            assert_eq!(cache.get("biz_activity", "a").await.unwrap(), None);
+            assert_eq!(cache.len(), 0);
+        });
+    }
+}
@@ -13,6 +13,17 @@ pub trait LogicDelete: Send + Sync {
     fn un_deleted(&self) -> i32;
     /// create_update_sql
     fn create_sql(&self, driver_type: &DriverType, table_name: &str, table_fields: &Vec<&str>, sql_where: &str) -> Result<String, rbatis_core::Error>;
+    /// un deleted condition of select,for example: "delete_flag = 1"
+    fn un_deleted_sql(&self) -> String {
+        format!("{} = {}", self.column(), self.un_deleted())
+    }
+    /// create restore(undelete) sql,set column back to un_deleted
+    fn create_restore_sql(&self, driver_type: &DriverType, table_name: &str, sql_where: &str) -> Result<String, rbatis_core::Error> {
+        if sql_where.trim().is_empty() {
+            return Err(Error::from("[rbatis] restore data must have where sql!"));
+        }
+        Ok(format!("UPDATE {} SET {} = {}", table_name, self.column(), self.un_deleted()) + sql_where)
+    }
 }
 
 
@@ -70,6 +81,57 @@ impl LogicDelete for RbatisLogicDeletePlugin {
     }
 }
 
+/// timestamp logic delete,for example an 'deleted_at' column:
+/// deleted is CURRENT_TIMESTAMP,un deleted is NULL
+pub struct TimeLogicDeletePlugin {
+    pub column: String,
+}
+
+impl TimeLogicDeletePlugin {
+    pub fn new(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+        }
+    }
+}
+
+impl LogicDelete for TimeLogicDeletePlugin {
+    fn column(&self) -> &str {
+        self.column.as_str()
+    }
+
+    /// not used,deleted is CURRENT_TIMESTAMP
+    fn deleted(&self) -> i32 {
+        1
+    }
+
+    /// not used,un deleted is NULL
+    fn un_deleted(&self) -> i32 {
+        0
+    }
+
+    fn create_sql(&self, driver_type: &DriverType, table_name: &str, table_fields: &Vec<&str>, sql_where: &str) -> Result<String, Error> {
+        return if table_fields.contains(&self.column.as_str()) {
+            Ok(format!("UPDATE {} SET {} = CURRENT_TIMESTAMP", table_name, self.column()) + sql_where)
+        } else if !sql_where.is_empty() {
+            Ok(format!("DELETE FROM {} {}", table_name, sql_where.trim_start()))
+        } else {
+            Err(Error::from("[rbatis] del data must have where sql!"))
+        };
+    }
+
+    fn un_deleted_sql(&self) -> String {
+        format!("{} IS NULL", self.column())
+    }
+
+    fn create_restore_sql(&self, driver_type: &DriverType, table_name: &str, sql_where: &str) -> Result<String, Error> {
+        if sql_where.trim().is_empty() {
+            return Err(Error::from("[rbatis] restore data must have where sql!"));
+        }
+        Ok(format!("UPDATE {} SET {} = NULL", table_name, self.column()) + sql_where)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,4 +158,23 @@ mod tests {
         let result = r.create_sql(&DriverType::Mysql, "test", &table_fields, sql_where);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_logic_delete_plugin_create_restore_sql() {
+        let r = RbatisLogicDeletePlugin::new("del");
+        let result = r.create_restore_sql(&DriverType::Mysql, "test", " WHERE name = 'zhangsan'").unwrap();
+        assert_eq!("UPDATE test SET del = 1 WHERE name = 'zhangsan'", &result);
+        assert!(r.create_restore_sql(&DriverType::Mysql, "test", "").is_err());
+    }
+
+    #[test]
+    fn test_time_logic_delete_plugin() {
+        let r = TimeLogicDeletePlugin::new("deleted_at");
+        let table_fields = vec!["name", "deleted_at"];
+        let result = r.create_sql(&DriverType::Mysql, "test", &table_fields, " WHERE id = 1").unwrap();
+        assert_eq!("UPDATE test SET deleted_at = CURRENT_TIMESTAMP WHERE id = 1", &result);
+        assert_eq!("deleted_at IS NULL", &r.un_deleted_sql());
+        let result = r.create_restore_sql(&DriverType::Mysql, "test", " WHERE id = 1").unwrap();
+        assert_eq!("UPDATE test SET deleted_at = NULL WHERE id = 1", &result);
+    }
 }
\ No newline at end of file
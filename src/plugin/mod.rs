@@ -1,2 +1,11 @@
 pub mod page;
-pub mod logic_delete;
\ No newline at end of file
+pub mod logic_delete;
+pub mod subscribe;
+pub mod outbox;
+pub mod seed;
+pub mod version_lock;
+pub mod auto_fill;
+pub mod tenant;
+pub mod slow_sql;
+pub mod audit;
+pub mod cache;
@@ -9,35 +9,84 @@ use rbatis_core::db::DriverType;
 
 use crate::sql::PageLimit;
 
-///default page plugin
+///page plugin,implement make_page_sql() for dialects without LIMIT/OFFSET(or custom strategies),
+///then set it to rbatis.page_plugin
 pub trait PagePlugin: Send + Sync {
     /// return 2 sql for select ,  (count_sql,select_sql)
-    fn create_page_sql(&self, driver_type: &DriverType, tx_id: &str, sql: &str, args: &Vec<serde_json::Value>, page: &dyn IPageRequest) -> Result<(String, String), rbatis_core::Error>;
-}
-
-#[derive(Copy, Clone, Debug)]
-pub struct RbatisPagePlugin {}
-
-
-impl PagePlugin for RbatisPagePlugin {
-    fn create_page_sql<>(&self, driver_type: &DriverType, tx_id: &str, sql: &str, args: &Vec<Value>, page: &dyn IPageRequest) -> Result<(String, String), rbatis_core::Error> {
+    fn create_page_sql(&self, driver_type: &DriverType, tx_id: &str, sql: &str, args: &Vec<serde_json::Value>, page: &dyn IPageRequest) -> Result<(String, String), rbatis_core::Error> {
         let mut sql = sql.to_owned();
         sql = sql.replace("select ", "SELECT ");
         sql = sql.replace("from ", "FROM ");
         sql = sql.trim().to_string();
-        let limit_sql = driver_type.page_limit_sql(page.offset(), page.get_size())?;
-        sql = sql + limit_sql.as_str();
         if !sql.starts_with("SELECT ") && !sql.contains("FROM ") {
             return Err(rbatis_core::Error::from("[rbatis] xml_fetch_page() sql must contains 'select ' And 'from '"));
         }
         let mut count_sql = sql.clone();
         if page.is_serch_count() {
-            //make count sql
-            let sql_vec: Vec<&str> = count_sql.split("FROM ").collect();
-            count_sql = "SELECT count(1) FROM ".to_string() + sql_vec[1];
+            count_sql = self.make_count_sql(driver_type, &sql)?;
         }
+        let sql = self.make_page_sql(driver_type, &sql, page)?;
         return Ok((count_sql, sql));
     }
+
+    /// return count sql of an select sql,the ORDER BY is useless for count
+    fn make_count_sql(&self, _driver_type: &DriverType, sql: &str) -> Result<String, rbatis_core::Error> {
+        if sql.starts_with("SELECT DISTINCT ") {
+            return Ok(format!("SELECT count(1) FROM ({}) rbatis_distinct", trim_order_by(sql)));
+        }
+        let sql_vec: Vec<&str> = sql.split("FROM ").collect();
+        let count_sql = "SELECT count(1) FROM ".to_string() + sql_vec[1..].join("FROM ").as_str();
+        return Ok(trim_order_by(&count_sql));
+    }
+
+    /// return select sql of one page
+    fn make_page_sql(&self, driver_type: &DriverType, sql: &str, page: &dyn IPageRequest) -> Result<String, rbatis_core::Error> {
+        if driver_type.eq(&DriverType::Mssql) {
+            return OffsetFetchPagePlugin {}.make_page_sql(driver_type, sql, page);
+        }
+        let limit_sql = driver_type.page_limit_sql(page.offset(), page.get_size())?;
+        return Ok(sql.to_string() + limit_sql.as_str());
+    }
+}
+
+///default page plugin, 'LIMIT ... OFFSET ...' of the driver type
+#[derive(Copy, Clone, Debug)]
+pub struct RbatisPagePlugin {}
+
+impl PagePlugin for RbatisPagePlugin {}
+
+///page plugin of 'OFFSET n ROWS FETCH NEXT m ROWS ONLY'(MSSQL 2012+,Oracle 12c+),
+///the sql without ORDER BY is ordered by '(SELECT NULL)'
+#[derive(Copy, Clone, Debug)]
+pub struct OffsetFetchPagePlugin {}
+
+impl PagePlugin for OffsetFetchPagePlugin {
+    fn make_page_sql(&self, _driver_type: &DriverType, sql: &str, page: &dyn IPageRequest) -> Result<String, rbatis_core::Error> {
+        let mut sql = sql.to_string();
+        if trim_order_by(&sql).eq(&sql) {
+            sql.push_str(" ORDER BY (SELECT NULL)");
+        }
+        return Ok(format!("{} OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", sql, page.offset(), page.get_size()));
+    }
+}
+
+///page plugin of Oracle ROWNUM,the 'rbatis_rn' column is added to the rows
+#[derive(Copy, Clone, Debug)]
+pub struct RowNumPagePlugin {}
+
+impl PagePlugin for RowNumPagePlugin {
+    fn make_page_sql(&self, _driver_type: &DriverType, sql: &str, page: &dyn IPageRequest) -> Result<String, rbatis_core::Error> {
+        return Ok(format!("SELECT * FROM (SELECT rbatis_t.*, ROWNUM rbatis_rn FROM ({}) rbatis_t WHERE ROWNUM <= {}) WHERE rbatis_rn > {}",
+                          sql, page.offset() + page.get_size(), page.offset()));
+    }
+}
+
+/// remove the tail ' ORDER BY ...'(not in an sub query)
+pub(crate) fn trim_order_by(sql: &str) -> String {
+    match sql.rfind(" ORDER BY ") {
+        Some(index) if !sql[index..].contains(')') => sql[..index].to_string(),
+        _ => sql.to_string(),
+    }
 }
 
 
@@ -53,6 +102,11 @@ pub trait IPageRequest: Send + Sync {
     fn set_current(&mut self, arg: u64);
     fn set_serch_count(&mut self, arg: bool);
 
+    ///custom count sql(use the same args as the select sql),None is the generated 'SELECT count(1) FROM ...'
+    fn get_count_sql(&self) -> Option<&str> {
+        None
+    }
+
     ///sum pages
     fn get_pages(&self) -> u64 {
         if self.get_size() == 0 {
@@ -106,7 +160,11 @@ pub struct PageRequest {
     pub size: u64,
     ///current index
     pub current: u64,
+    ///false will skip the count sql(for example infinite scroll),total is 0
     pub serch_count: bool,
+    ///custom count sql,for example an cheaper count sql of an join
+    #[serde(default)]
+    pub count_sql: Option<String>,
 }
 
 impl PageRequest {
@@ -124,8 +182,23 @@ impl PageRequest {
             size,
             current,
             serch_count: true,
+            count_sql: None,
         };
     }
+
+    /// page request without count sql
+    pub fn new_no_count(current: u64, size: u64) -> Self {
+        let mut req = PageRequest::new(current, size);
+        req.serch_count = false;
+        req
+    }
+
+    /// page request with custom count sql
+    pub fn new_count_sql(current: u64, size: u64, count_sql: &str) -> Self {
+        let mut req = PageRequest::new(current, size);
+        req.count_sql = Some(count_sql.to_string());
+        req
+    }
 }
 
 impl Default for PageRequest {
@@ -135,6 +208,7 @@ impl Default for PageRequest {
             size: 10,
             current: 1,
             serch_count: true,
+            count_sql: None,
         };
     }
 }
@@ -170,6 +244,10 @@ impl IPageRequest for PageRequest {
     fn set_serch_count(&mut self, arg: bool) {
         self.serch_count = arg;
     }
+
+    fn get_count_sql(&self) -> Option<&str> {
+        self.count_sql.as_ref().map(|x| x.as_str())
+    }
 }
 
 impl ToString for PageRequest {
@@ -285,7 +363,31 @@ impl<T> ToString for Page<T> where T: Send + Sync + Serialize {
 
 
 mod test {
-    use crate::plugin::page::{IPage, IPageRequest, Page};
+    use rbatis_core::db::DriverType;
+
+    use crate::plugin::page::{IPage, IPageRequest, OffsetFetchPagePlugin, Page, PagePlugin, PageRequest, RbatisPagePlugin, RowNumPagePlugin};
+
+    #[test]
+    pub fn test_dialect_page_plugin() {
+        let (count_sql, sql) = OffsetFetchPagePlugin {}.create_page_sql(&DriverType::None, "", "SELECT * FROM biz_activity ORDER BY create_time DESC", &vec![], &PageRequest::new(2, 10)).unwrap();
+        assert_eq!(count_sql, "SELECT count(1) FROM biz_activity");
+        assert_eq!(sql, "SELECT * FROM biz_activity ORDER BY create_time DESC OFFSET 10 ROWS FETCH NEXT 10 ROWS ONLY");
+        let (_, sql) = OffsetFetchPagePlugin {}.create_page_sql(&DriverType::None, "", "SELECT * FROM biz_activity", &vec![], &PageRequest::new(1, 10)).unwrap();
+        assert_eq!(sql, "SELECT * FROM biz_activity ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY");
+        let (_, sql) = RbatisPagePlugin {}.create_page_sql(&DriverType::Mssql, "", "SELECT * FROM biz_activity", &vec![], &PageRequest::new(2, 10)).unwrap();
+        assert_eq!(sql, "SELECT * FROM biz_activity ORDER BY (SELECT NULL) OFFSET 10 ROWS FETCH NEXT 10 ROWS ONLY");
+        let (_, sql) = RowNumPagePlugin {}.create_page_sql(&DriverType::None, "", "SELECT * FROM biz_activity", &vec![], &PageRequest::new(2, 10)).unwrap();
+        assert_eq!(sql, "SELECT * FROM (SELECT rbatis_t.*, ROWNUM rbatis_rn FROM (SELECT * FROM biz_activity) rbatis_t WHERE ROWNUM <= 20) WHERE rbatis_rn > 10");
+    }
+
+    #[test]
+    pub fn test_page_request_count_sql() {
+        let req = PageRequest::new_count_sql(1, 10, "SELECT count(1) FROM biz_activity");
+        assert_eq!(req.get_count_sql(), Some("SELECT count(1) FROM biz_activity"));
+        let req: PageRequest = serde_json::from_str(r#"{"total":0,"size":10,"current":1,"serch_count":false}"#).unwrap();
+        assert_eq!(req.get_count_sql(), None);
+        assert!(!PageRequest::new_no_count(1, 10).is_serch_count());
+    }
 
     #[test]
     pub fn test_page() {
@@ -301,4 +403,13 @@ mod test {
         println!("page_string:{}", page.to_string());
         assert_eq!(page.offset(), 10);
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_create_page_sql() {
+        let (count_sql, sql) = RbatisPagePlugin {}.create_page_sql(&DriverType::Mysql, "", "SELECT * FROM biz_activity WHERE delete_flag = 1 ORDER BY create_time DESC", &vec![], &PageRequest::new(2, 10)).unwrap();
+        assert_eq!(count_sql, "SELECT count(1) FROM biz_activity WHERE delete_flag = 1");
+        assert!(sql.starts_with("SELECT * FROM biz_activity WHERE delete_flag = 1 ORDER BY create_time DESC"));
+        let (count_sql, _) = RbatisPagePlugin {}.create_page_sql(&DriverType::Mysql, "", "SELECT DISTINCT status FROM biz_activity", &vec![], &PageRequest::new(1, 10)).unwrap();
+        assert_eq!(count_sql, "SELECT count(1) FROM (SELECT DISTINCT status FROM biz_activity) rbatis_distinct");
+    }
+}
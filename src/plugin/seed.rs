@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+use rbatis_core::db::DriverType;
+use rbatis_core::Error;
+use rbatis_core::Result;
+
+use crate::crud::{after_write_table, CRUDEnable, make_save_batch_map_sql};
+use crate::plugin::subscribe::TableOp;
+use crate::rbatis::Rbatis;
+
+/// insert rows of one table
+pub struct SeedTable {
+    pub table_name: String,
+    /// tables referenced by foreign keys,from #[crud(references = "..")]
+    pub references: Vec<String>,
+    pub rows: Vec<Map<String, Value>>,
+    make_sql: fn(&DriverType, &[Map<String, Value>]) -> Result<(String, Vec<Value>)>,
+}
+
+impl SeedTable {
+    /// return (sql,args) of an multi values insert
+    pub fn make_sql(&self, driver_type: &DriverType) -> Result<(String, Vec<Value>)> {
+        (self.make_sql)(driver_type, &self.rows)
+    }
+}
+
+/// heterogeneous entities insert in one transaction,for example:
+///
+/// let mut batch = SeedBatch::new();
+/// batch.push(&orders)?.push(&users)?;
+/// rb.begin("tx1").await?;
+/// rb.save_seed("tx1", &batch).await?;//users is inserted before orders(order.user_id references users)
+/// rb.commit("tx1").await?;
+#[derive(Default)]
+pub struct SeedBatch {
+    pub tables: Vec<SeedTable>,
+}
+
+impl SeedBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// push entities of one table
+    pub fn push<T>(&mut self, args: &[T]) -> Result<&mut Self> where T: CRUDEnable {
+        if args.is_empty() {
+            return Ok(self);
+        }
+        let mut rows = Vec::with_capacity(args.len());
+        for x in args {
+            //field value map not depend on driver type
+            rows.push(T::make_field_value_map(&DriverType::None, x)?);
+        }
+        let table_name = T::table_name();
+        let mut references = vec![];
+        for x in T::table_columns_meta() {
+            if let Some(r) = x.references {
+                if r.ne(&table_name) && !references.contains(&r) {
+                    references.push(r);
+                }
+            }
+        }
+        self.tables.push(SeedTable {
+            table_name,
+            references,
+            rows,
+            make_sql: make_save_batch_map_sql::<T>,
+        });
+        Ok(self)
+    }
+
+    /// order tables by foreign key dependencies(referenced table first),keep push order if no dependency.
+    /// self reference is ignored. if allow_cycle is false,cycle dependencies return an error,
+    /// else the tables in cycle keep push order(the constraints must be deferred)
+    pub fn sorted_tables(&self, allow_cycle: bool) -> Result<Vec<&SeedTable>> {
+        let mut done = vec![false; self.tables.len()];
+        let mut sorted = Vec::with_capacity(self.tables.len());
+        while sorted.len() < self.tables.len() {
+            let next = (0..self.tables.len()).find(|i| {
+                !done[*i] && self.tables[*i].references.iter().all(|r| {
+                    self.tables.iter().enumerate().all(|(j, t)| done[j] || t.table_name.ne(r))
+                })
+            });
+            match next {
+                Some(i) => {
+                    done[i] = true;
+                    sorted.push(&self.tables[i]);
+                }
+                None => {
+                    if !allow_cycle {
+                        let cycle: Vec<&str> = self.tables.iter().enumerate().filter(|(i, _)| !done[*i]).map(|(_, t)| t.table_name.as_str()).collect();
+                        return Err(Error::from(format!("[rbatis] seed tables have cycle foreign key dependencies: {:?}", cycle)));
+                    }
+                    for (i, t) in self.tables.iter().enumerate() {
+                        if !done[i] {
+                            done[i] = true;
+                            sorted.push(t);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(sorted)
+    }
+}
+
+/// insert an SeedBatch ordered by foreign key dependencies
+#[async_trait]
+pub trait Seed {
+    /// tx_id must not be empty. on Postgres exec 'SET CONSTRAINTS ALL DEFERRED' first,
+    /// so DEFERRABLE constraints(and cycle dependencies) are checked on commit.
+    /// return total affected rows
+    async fn save_seed(&self, tx_id: &str, batch: &SeedBatch) -> Result<u64>;
+}
+
+#[async_trait]
+impl Seed for Rbatis {
+    async fn save_seed(&self, tx_id: &str, batch: &SeedBatch) -> Result<u64> {
+        if tx_id.is_empty() {
+            return Err(Error::from("[rbatis] save_seed() tx_id can not be empty!"));
+        }
+        let driver_type = self.driver_type()?;
+        let defer = driver_type.eq(&DriverType::Postgres);
+        let tables = batch.sorted_tables(defer)?;
+        if defer {
+            self.exec(tx_id, "SET CONSTRAINTS ALL DEFERRED").await?;
+        }
+        let mut affected = 0;
+        for table in tables {
+            let (sql, args) = table.make_sql(&driver_type)?;
+            let rows = self.exec_prepare(tx_id, sql.as_str(), &args).await?;
+            let ids = table.rows.iter().filter_map(|x| x.get("id")).filter(|x| !x.is_null()).cloned().collect();
+            after_write_table(self, tx_id, table.table_name.as_str(), TableOp::Insert, ids, None, Some(json!(table.rows)), rows).await?;
+            affected += rows;
+        }
+        return Ok(affected);
+    }
+}
+
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use rbatis_core::db::DriverType;
+
+    use crate::crud::{ColumnMeta, CRUDEnable};
+    use crate::plugin::seed::SeedBatch;
+
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct User {
+        pub id: Option<String>,
+    }
+
+    impl CRUDEnable for User {
+        type IdType = String;
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct UserOrder {
+        pub id: Option<String>,
+        pub user_id: Option<String>,
+    }
+
+    impl CRUDEnable for UserOrder {
+        type IdType = String;
+
+        fn table_columns_meta() -> Vec<ColumnMeta> {
+            vec![ColumnMeta {
+                name: "user_id".to_string(),
+                references: Some("user".to_string()),
+                ..ColumnMeta::default()
+            }]
+        }
+    }
+
+    #[test]
+    pub fn test_sorted_tables() {
+        let mut batch = SeedBatch::new();
+        batch.push(&[UserOrder { id: Some("o1".to_string()), user_id: Some("u1".to_string()) }]).unwrap()
+            .push(&[User { id: Some("u1".to_string()) }]).unwrap();
+        let tables: Vec<&str> = batch.sorted_tables(false).unwrap().iter().map(|x| x.table_name.as_str()).collect();
+        assert_eq!(tables, vec!["user", "user_order"]);
+        let (sql, args) = batch.tables[1].make_sql(&DriverType::Postgres).unwrap();
+        assert_eq!(sql, "INSERT INTO user (id) VALUES ( $1 )");
+        assert_eq!(args, vec![json!("u1")]);
+    }
+}
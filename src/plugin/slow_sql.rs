@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+use serde_json::Value;
+
+/// an statement run longer than the threshold
+#[derive(Clone, Debug)]
+pub struct SlowSqlEvent {
+    pub tx_id: String,
+    pub sql: String,
+    pub args: Vec<Value>,
+    pub elapsed: Duration,
+}
+
+/// Slow Sql Plugin trait
+pub trait SlowSql: Send + Sync {
+    /// statements run longer than it are slow
+    fn threshold(&self) -> Duration;
+    /// called with the slow statement
+    fn on_slow_sql(&self, event: &SlowSqlEvent);
+}
+
+/// log the slow statements(warn level),and send them to the collector(for example an metrics system)
+pub struct RbatisSlowSqlPlugin {
+    pub threshold: Duration,
+    pub collector: Option<Arc<dyn Fn(&SlowSqlEvent) + Send + Sync>>,
+}
+
+impl RbatisSlowSqlPlugin {
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            collector: None,
+        }
+    }
+
+    pub fn set_collector<F>(mut self, f: F) -> Self
+        where F: Fn(&SlowSqlEvent) + Send + Sync + 'static {
+        self.collector = Some(Arc::new(f));
+        self
+    }
+}
+
+impl SlowSql for RbatisSlowSqlPlugin {
+    fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    fn on_slow_sql(&self, event: &SlowSqlEvent) {
+        warn!("[rbatis] [{}] SlowSql {:?} ==> {},Args ==> {}", event.tx_id, event.elapsed, event.sql, serde_json::to_string(&event.args).unwrap_or("".to_string()));
+        if let Some(collector) = &self.collector {
+            collector(event);
+        }
+    }
+}
+
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use crate::plugin::slow_sql::{RbatisSlowSqlPlugin, SlowSql, SlowSqlEvent};
+
+    #[test]
+    fn test_slow_sql_collector() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let c = count.clone();
+        let plugin = RbatisSlowSqlPlugin::new(Duration::from_millis(100)).set_collector(move |e| {
+            assert_eq!(e.sql, "select * from biz_activity");
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+        plugin.on_slow_sql(&SlowSqlEvent {
+            tx_id: "".to_string(),
+            sql: "select * from biz_activity".to_string(),
+            args: vec![],
+            elapsed: Duration::from_millis(200),
+        });
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// table write operation
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableOp {
+    Insert,
+    Update,
+    Delete,
+    /// all rows are deleted,rows_affected may be 0(the driver not count them)
+    Truncate,
+}
+
+/// table write event,published after INSERT/UPDATE/DELETE success through CRUD(in tx,after commit)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableEvent {
+    pub table: String,
+    pub op: TableOp,
+    /// affected ids,empty if unknown(for example remove_by_wrapper)
+    pub ids: Vec<Value>,
+    pub rows_affected: u64,
+}
+
+impl TableEvent {
+    pub fn new(table: &str, op: TableOp, ids: Vec<Value>, rows_affected: u64) -> Self {
+        Self {
+            table: table.to_string(),
+            op,
+            ids,
+            rows_affected,
+        }
+    }
+}
+
+/// table write subscriber
+pub type TableSubscriber = Arc<dyn Fn(&TableEvent) + Send + Sync>;
+
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use serde_json::json;
+
+    use crate::plugin::subscribe::{TableEvent, TableOp};
+    use crate::rbatis::Rbatis;
+
+    #[test]
+    pub fn test_subscribe_table() {
+        let rb = Rbatis::new();
+        let count = Arc::new(AtomicU64::new(0));
+        let c = count.clone();
+        rb.subscribe_table("biz_activity", move |e| {
+            c.fetch_add(e.ids.len() as u64, Ordering::SeqCst);
+        });
+        rb.publish_table_event(&TableEvent::new("biz_activity", TableOp::Delete, vec![json!("1"), json!("2")], 2));
+        rb.publish_table_event(&TableEvent::new("other_table", TableOp::Delete, vec![json!("3")], 1));
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        //clones share the subscribers
+        let rb_clone = rb.clone();
+        rb_clone.publish_table_event(&TableEvent::new("biz_activity", TableOp::Insert, vec![json!("4")], 1));
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+
+        //the callback can subscribe(not dead lock)
+        let rb2 = rb.clone();
+        rb.subscribe_table("biz_activity", move |_| {
+            rb2.subscribe_table("biz_activity", |_| {});
+        });
+        rb.publish_table_event(&TableEvent::new("biz_activity", TableOp::Insert, vec![json!("5")], 1));
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+        assert_eq!(rb.table_subscribers.get("biz_activity").unwrap().len(), 3);
+    }
+}
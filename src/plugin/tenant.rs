@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+/// Multi Tenant Plugin trait,the generated SELECT/UPDATE/DELETE of CRUD will have the condition 'column = tenant_id'
+pub trait Tenant: Send + Sync {
+    /// database column,for example "tenant_id"
+    fn column(&self) -> &str;
+    /// current tenant id,None is an error(the sql will not run)
+    fn tenant_id(&self) -> Option<Value>;
+    /// the table not have the tenant column(or shared by all tenants)
+    fn is_ignore_table(&self, table_name: &str) -> bool;
+}
+
+/// TenantPlugin,the tenant id is from an callback,for example an task local of current request:
+///
+/// rb.tenant_plugin = Some(Arc::new(RbatisTenantPlugin::new("tenant_id", || CURRENT_TENANT.try_with(|x| json!(x)).ok())
+///     .ignore_table("sys_dict")));
+pub struct RbatisTenantPlugin {
+    pub column: String,
+    pub ignore_tables: Vec<String>,
+    pub tenant_id: Arc<dyn Fn() -> Option<Value> + Send + Sync>,
+}
+
+impl RbatisTenantPlugin {
+    pub fn new<F>(column: &str, tenant_id: F) -> Self
+        where F: Fn() -> Option<Value> + Send + Sync + 'static {
+        Self {
+            column: column.to_string(),
+            ignore_tables: vec![],
+            tenant_id: Arc::new(tenant_id),
+        }
+    }
+
+    pub fn ignore_table(mut self, table_name: &str) -> Self {
+        self.ignore_tables.push(table_name.to_string());
+        self
+    }
+}
+
+impl Tenant for RbatisTenantPlugin {
+    fn column(&self) -> &str {
+        self.column.as_str()
+    }
+
+    fn tenant_id(&self) -> Option<Value> {
+        (self.tenant_id)()
+    }
+
+    fn is_ignore_table(&self, table_name: &str) -> bool {
+        self.ignore_tables.iter().any(|x| x.eq(table_name))
+    }
+}
@@ -0,0 +1,30 @@
+/// Optimistic Lock(version) Plugin trait
+pub trait VersionLock: Send + Sync {
+    /// database column,for example "version"
+    fn column(&self) -> &str;
+    /// set sql of update,for example: "version = version + 1"
+    fn set_sql(&self) -> String {
+        format!("{} = {} + 1", self.column(), self.column())
+    }
+}
+
+/// the update of an entity with not null version column will be:
+/// UPDATE t SET ..., version = version + 1 WHERE id = ? AND version = ?
+/// zero rows affected return Error::VersionConflict
+pub struct RbatisVersionLockPlugin {
+    pub column: String,
+}
+
+impl RbatisVersionLockPlugin {
+    pub fn new(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+        }
+    }
+}
+
+impl VersionLock for RbatisVersionLockPlugin {
+    fn column(&self) -> &str {
+        self.column.as_str()
+    }
+}
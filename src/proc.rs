@@ -0,0 +1,155 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use rbatis_core::convert::StmtConvert;
+use rbatis_core::db::DriverType;
+use rbatis_core::decode::{json_decode_scalar, ResultSet};
+use rbatis_core::Error;
+use rbatis_core::Result;
+
+/// an OUT(value is None) or INOUT(value is Some) parameter of stored procedure,
+/// the OUT parameters are passed after the in args
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcOut {
+    pub name: String,
+    pub value: Option<Value>,
+}
+
+impl ProcOut {
+    pub fn out(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: None,
+        }
+    }
+
+    pub fn in_out(name: &str, value: Value) -> Self {
+        Self {
+            name: name.to_string(),
+            value: Some(value),
+        }
+    }
+}
+
+/// result of rb.call_proc(),the OUT/INOUT values and the result sets
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProcResult {
+    pub outs: Map<String, Value>,
+    pub sets: Vec<ResultSet>,
+}
+
+impl ProcResult {
+    /// decode the OUT/INOUT value
+    pub fn out<T>(&self, name: &str) -> Result<T> where T: DeserializeOwned {
+        json_decode_scalar(self.outs.get(name).cloned().unwrap_or(Value::Null))
+    }
+}
+
+/// the statements of an procedure call
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallSql {
+    /// (sql,args) run before the call,mysql set the session variables of the parameters
+    pub prepare: Option<(String, Vec<Value>)>,
+    pub call: String,
+    pub call_args: Vec<Value>,
+    /// sql select the OUT/INOUT values after the call(mysql)
+    pub select_outs: Option<String>,
+}
+
+/// make the driver call sql:
+/// mysql: SET @rbatis_p0 = ?,... ; CALL name(@rbatis_p0,...) ; SELECT @rbatis_p1 AS name,...
+/// postgres: CALL name($1,...,NULL),the OUT/INOUT values are returned as an row(named by the procedure parameters)
+pub fn make_call_sql(driver_type: &DriverType, name: &str, in_args: &[Value], outs: &[ProcOut]) -> Result<CallSql> {
+    check_name(name)?;
+    for out in outs {
+        check_name(&out.name)?;
+    }
+    match driver_type {
+        DriverType::Mysql => {
+            let mut sets = vec![];
+            let mut params = vec![];
+            let mut args = vec![];
+            for (index, arg) in in_args.iter().enumerate() {
+                sets.push(format!("@rbatis_p{} = ?", index));
+                params.push(format!("@rbatis_p{}", index));
+                args.push(arg.clone());
+            }
+            let mut select_outs = vec![];
+            for (index, out) in outs.iter().enumerate() {
+                let var = format!("@rbatis_p{}", in_args.len() + index);
+                match &out.value {
+                    Some(v) => {
+                        sets.push(format!("{} = ?", var));
+                        args.push(v.clone());
+                    }
+                    None => sets.push(format!("{} = NULL", var)),
+                }
+                select_outs.push(format!("{} AS `{}`", var, out.name));
+                params.push(var);
+            }
+            Ok(CallSql {
+                prepare: if sets.is_empty() { None } else { Some((format!("SET {}", sets.join(",")), args)) },
+                call: format!("CALL {}({})", name, params.join(",")),
+                call_args: vec![],
+                select_outs: if select_outs.is_empty() { None } else { Some(format!("SELECT {}", select_outs.join(","))) },
+            })
+        }
+        DriverType::Postgres => {
+            let mut params = vec![];
+            let mut args = vec![];
+            for arg in in_args {
+                params.push(driver_type.stmt_convert(args.len()).trim().to_string());
+                args.push(arg.clone());
+            }
+            for out in outs {
+                match &out.value {
+                    Some(v) => {
+                        params.push(driver_type.stmt_convert(args.len()).trim().to_string());
+                        args.push(v.clone());
+                    }
+                    None => params.push("NULL".to_string()),
+                }
+            }
+            Ok(CallSql {
+                prepare: None,
+                call: format!("CALL {}({})", name, params.join(",")),
+                call_args: args,
+                select_outs: None,
+            })
+        }
+        _ => Err(Error::from(format!("[rbatis] call procedure not support driver: {:?}", driver_type))),
+    }
+}
+
+/// name of procedure(can have schema) or parameter
+fn check_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+        return Err(Error::from(format!("[rbatis] illegal procedure name: '{}'", name)));
+    }
+    Ok(())
+}
+
+mod test {
+    use serde_json::json;
+
+    use rbatis_core::db::DriverType;
+
+    use crate::proc::{make_call_sql, ProcOut};
+
+    #[test]
+    fn test_make_call_sql() {
+        let outs = vec![ProcOut::out("total"), ProcOut::in_out("status", json!(1))];
+        let sql = make_call_sql(&DriverType::Mysql, "report_activity", &[json!("a")], &outs).unwrap();
+        assert_eq!(sql.prepare, Some(("SET @rbatis_p0 = ?,@rbatis_p1 = NULL,@rbatis_p2 = ?".to_string(), vec![json!("a"), json!(1)])));
+        assert_eq!(sql.call, "CALL report_activity(@rbatis_p0,@rbatis_p1,@rbatis_p2)");
+        assert_eq!(sql.select_outs, Some("SELECT @rbatis_p1 AS `total`,@rbatis_p2 AS `status`".to_string()));
+
+        let sql = make_call_sql(&DriverType::Postgres, "public.report_activity", &[json!("a")], &outs).unwrap();
+        assert_eq!(sql.call, "CALL public.report_activity($1,NULL,$2)");
+        assert_eq!(sql.call_args, vec![json!("a"), json!(1)]);
+        assert_eq!(sql.prepare, None);
+
+        assert!(make_call_sql(&DriverType::Mysql, "report;drop table biz_activity", &[], &[]).is_err());
+        assert!(make_call_sql(&DriverType::Sqlite, "report_activity", &[], &[]).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use rbatis_core::db::DriverType;
+use rbatis_core::Error;
+
+use crate::sql::TimeBucket;
+
+/// time bucket interval
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Interval {
+    Minute,
+    Hour,
+    Day,
+    /// week start at monday
+    Week,
+    Month,
+    Year,
+}
+
+/// aggregate function,the String is column name
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum Agg {
+    Count,
+    Sum(String),
+    Avg(String),
+    Min(String),
+    Max(String),
+}
+
+impl Agg {
+    pub fn sql(&self) -> String {
+        match self {
+            Agg::Count => "count(1)".to_string(),
+            Agg::Sum(column) => format!("sum({})", column),
+            Agg::Avg(column) => format!("avg({})", column),
+            Agg::Min(column) => format!("min({})", column),
+            Agg::Max(column) => format!("max({})", column),
+        }
+    }
+}
+
+impl TimeBucket for DriverType {
+    fn time_bucket_sql(&self, column: &str, interval: &Interval) -> rbatis_core::Result<String> {
+        return match self {
+            DriverType::Mysql => {
+                Ok(match interval {
+                    Interval::Minute => format!("DATE_FORMAT({}, '%Y-%m-%d %H:%i:00')", column),
+                    Interval::Hour => format!("DATE_FORMAT({}, '%Y-%m-%d %H:00:00')", column),
+                    Interval::Day => format!("DATE_FORMAT({}, '%Y-%m-%d')", column),
+                    Interval::Week => format!("DATE_FORMAT(DATE_SUB({}, INTERVAL WEEKDAY({}) DAY), '%Y-%m-%d')", column, column),
+                    Interval::Month => format!("DATE_FORMAT({}, '%Y-%m')", column),
+                    Interval::Year => format!("DATE_FORMAT({}, '%Y')", column),
+                })
+            }
+            DriverType::Postgres => {
+                Ok(match interval {
+                    Interval::Minute => format!("to_char(date_trunc('minute', {}), 'YYYY-MM-DD HH24:MI:00')", column),
+                    Interval::Hour => format!("to_char(date_trunc('hour', {}), 'YYYY-MM-DD HH24:00:00')", column),
+                    Interval::Day => format!("to_char(date_trunc('day', {}), 'YYYY-MM-DD')", column),
+                    Interval::Week => format!("to_char(date_trunc('week', {}), 'YYYY-MM-DD')", column),
+                    Interval::Month => format!("to_char(date_trunc('month', {}), 'YYYY-MM')", column),
+                    Interval::Year => format!("to_char(date_trunc('year', {}), 'YYYY')", column),
+                })
+            }
+            DriverType::Sqlite => {
+                Ok(match interval {
+                    Interval::Minute => format!("strftime('%Y-%m-%d %H:%M:00', {})", column),
+                    Interval::Hour => format!("strftime('%Y-%m-%d %H:00:00', {})", column),
+                    Interval::Day => format!("strftime('%Y-%m-%d', {})", column),
+                    Interval::Week => format!("strftime('%Y-%m-%d', {}, 'weekday 0', '-6 days')", column),
+                    Interval::Month => format!("strftime('%Y-%m', {})", column),
+                    Interval::Year => format!("strftime('%Y', {})", column),
+                })
+            }
+            DriverType::Mssql => {
+                Ok(match interval {
+                    Interval::Minute => format!("FORMAT({}, 'yyyy-MM-dd HH:mm:00')", column),
+                    Interval::Hour => format!("FORMAT({}, 'yyyy-MM-dd HH:00:00')", column),
+                    Interval::Day => format!("FORMAT({}, 'yyyy-MM-dd')", column),
+                    Interval::Week => format!("FORMAT(DATEADD(day, -((DATEPART(weekday, {}) + @@DATEFIRST - 2) % 7), {}), 'yyyy-MM-dd')", column, column),
+                    Interval::Month => format!("FORMAT({}, 'yyyy-MM')", column),
+                    Interval::Year => format!("FORMAT({}, 'yyyy')", column),
+                })
+            }
+            DriverType::Oracle => {
+                Ok(match interval {
+                    Interval::Minute => format!("TO_CHAR({}, 'YYYY-MM-DD HH24:MI:00')", column),
+                    Interval::Hour => format!("TO_CHAR({}, 'YYYY-MM-DD HH24:00:00')", column),
+                    Interval::Day => format!("TO_CHAR({}, 'YYYY-MM-DD')", column),
+                    Interval::Week => format!("TO_CHAR(TRUNC({}, 'IW'), 'YYYY-MM-DD')", column),
+                    Interval::Month => format!("TO_CHAR({}, 'YYYY-MM')", column),
+                    Interval::Year => format!("TO_CHAR({}, 'YYYY')", column),
+                })
+            }
+            DriverType::ClickHouse => {
+                Ok(match interval {
+                    Interval::Minute => format!("formatDateTime({}, '%Y-%m-%d %H:%M:00')", column),
+                    Interval::Hour => format!("formatDateTime({}, '%Y-%m-%d %H:00:00')", column),
+                    Interval::Day => format!("formatDateTime({}, '%Y-%m-%d')", column),
+                    Interval::Week => format!("formatDateTime(toMonday({}), '%Y-%m-%d')", column),
+                    Interval::Month => format!("formatDateTime({}, '%Y-%m')", column),
+                    Interval::Year => format!("formatDateTime({}, '%Y')", column),
+                })
+            }
+            DriverType::None => {
+                Err(Error::from(format!("[rbatis] not support now for DriverType:{:?}", DriverType::None)))
+            }
+        };
+    }
+}
+
+#[test]
+pub fn test_time_bucket() {
+    let sql = DriverType::Mysql.time_bucket_sql("create_time", &Interval::Day).unwrap();
+    assert_eq!(sql, "DATE_FORMAT(create_time, '%Y-%m-%d')");
+    let sql = DriverType::Postgres.time_bucket_sql("create_time", &Interval::Month).unwrap();
+    assert_eq!(sql, "to_char(date_trunc('month', create_time), 'YYYY-MM')");
+    assert_eq!(Agg::Sum("amount".to_string()).sql(), "sum(amount)");
+}
@@ -2,16 +2,28 @@ use serde_json::Value;
 
 use rbatis_core::convert::StmtConvert;
 use rbatis_core::db::DriverType;
+use rbatis_core::dialect::get_dialect;
 
 use crate::sql::Date;
 
 impl Date for DriverType {
     fn date_convert(&self, value: &Value, index: usize) -> rbatis_core::Result<(String, Value)> {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.date_convert(value, index);
+        }
         let mut sql = String::new();
         match self {
             DriverType::Postgres => {
                 sql = format!("cast({} as timestamp)", self.stmt_convert(index).as_str());
             }
+            DriverType::Oracle => {
+                sql = format!("TO_DATE({}, 'YYYY-MM-DD HH24:MI:SS')", self.stmt_convert(index).trim());
+                //DATE have no fraction of second and time zone
+                if let Value::String(v) = value {
+                    let v: String = v.replacen('T', " ", 1).chars().take(19).collect();
+                    return Ok((sql, Value::String(v)));
+                }
+            }
             _ => {
                 sql = self.stmt_convert(index);
             }
@@ -21,4 +33,8 @@ impl Date for DriverType {
 }
 
 #[test]
-pub fn test_date() {}
\ No newline at end of file
+pub fn test_date() {
+    let (sql, value) = DriverType::Oracle.date_convert(&Value::String("2020-01-02T03:04:05.678+08:00".to_string()), 0).unwrap();
+    assert_eq!(sql, "TO_DATE(:1, 'YYYY-MM-DD HH24:MI:SS')");
+    assert_eq!(value, Value::String("2020-01-02 03:04:05".to_string()));
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use rbatis_core::db::DriverType;
+use rbatis_core::dialect::get_dialect;
+
+use crate::sql::QuoteIdentifier;
+
+impl QuoteIdentifier for DriverType {
+    fn quote_identifier(&self, name: &str) -> String {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.quote_identifier(name);
+        }
+        match self {
+            DriverType::Mysql | DriverType::ClickHouse => {
+                format!("`{}`", name.replace('`', "``"))
+            }
+            DriverType::Mssql => {
+                format!("[{}]", name.replace(']', "]]"))
+            }
+            _ => {
+                format!("\"{}\"", name.replace('"', "\"\""))
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_quote_identifier() {
+    assert_eq!(DriverType::Mysql.quote_identifier("name"), "`name`");
+    assert_eq!(DriverType::Postgres.quote_identifier("name"), "\"name\"");
+    assert_eq!(DriverType::Mssql.quote_identifier("na]me"), "[na]]me]");
+}
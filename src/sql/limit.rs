@@ -1,9 +1,14 @@
 use rbatis_core::db::DriverType;
+use rbatis_core::dialect::get_dialect;
 
+use crate::plugin::page::trim_order_by;
 use crate::sql::PageLimit;
 
 impl PageLimit for DriverType {
     fn page_limit_sql(&self, offset: u64, size: u64) -> rbatis_core::Result<String> {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.page_limit_sql(offset, size);
+        }
         return match self {
             DriverType::Mysql => {
                 Ok(format!(" LIMIT {},{}", offset, size))
@@ -11,14 +16,101 @@ impl PageLimit for DriverType {
             DriverType::Postgres => {
                 Ok(format!(" LIMIT {} OFFSET {}", size, offset))
             }
-            DriverType::Sqlite => {
+            DriverType::Sqlite | DriverType::ClickHouse => {
                 Ok(format!(" LIMIT {} OFFSET {}", size, offset))
             }
+            DriverType::Mssql => {
+                //the sql must have an ORDER BY
+                Ok(format!(" OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", offset, size))
+            }
+            DriverType::Oracle => {
+                //Oracle 12c+,use RowNumPagePlugin for the older
+                Ok(format!(" OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", offset, size))
+            }
             DriverType::None => {
                 Err(rbatis_core::Error::from(format!("[rbatis] not support now for DriverType:{:?}", DriverType::None)))
             }
         };
     }
+
+    fn limit_offset_sql(&self, limit: Option<u64>, offset: Option<u64>) -> rbatis_core::Result<String> {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.limit_offset_sql(limit, offset);
+        }
+        let mut sql = String::new();
+        match self {
+            DriverType::Mysql | DriverType::Sqlite | DriverType::ClickHouse => {
+                //offset must have an limit
+                let no_limit = if self.eq(&DriverType::Sqlite) { "-1" } else { "18446744073709551615" };
+                match (limit, offset) {
+                    (Some(limit), _) => sql.push_str(format!(" LIMIT {}", limit).as_str()),
+                    (None, Some(_)) => sql.push_str(format!(" LIMIT {}", no_limit).as_str()),
+                    (None, None) => {}
+                }
+            }
+            DriverType::Postgres => {
+                if let Some(limit) = limit {
+                    sql.push_str(format!(" LIMIT {}", limit).as_str());
+                }
+            }
+            DriverType::Mssql => {
+                if limit.is_none() && offset.is_none() {
+                    return Ok(sql);
+                }
+                //the sql must have an ORDER BY
+                sql.push_str(format!(" OFFSET {} ROWS", offset.unwrap_or(0)).as_str());
+                if let Some(limit) = limit {
+                    sql.push_str(format!(" FETCH NEXT {} ROWS ONLY", limit).as_str());
+                }
+                return Ok(sql);
+            }
+            DriverType::Oracle => {
+                if let Some(offset) = offset {
+                    sql.push_str(format!(" OFFSET {} ROWS", offset).as_str());
+                }
+                if let Some(limit) = limit {
+                    sql.push_str(format!(" FETCH NEXT {} ROWS ONLY", limit).as_str());
+                }
+                return Ok(sql);
+            }
+            DriverType::None => {
+                return Err(rbatis_core::Error::from(format!("[rbatis] not support now for DriverType:{:?}", DriverType::None)));
+            }
+        }
+        if let Some(offset) = offset {
+            sql.push_str(format!(" OFFSET {}", offset).as_str());
+        }
+        Ok(sql)
+    }
+
+    fn limit_select_sql(&self, sql: &str, limit: Option<u64>, offset: Option<u64>) -> rbatis_core::Result<String> {
+        if let Some(dialect) = get_dialect(self) {
+            return dialect.limit_select_sql(sql, limit, offset);
+        }
+        if limit.is_none() && offset.is_none() {
+            return Ok(sql.to_string());
+        }
+        if self.eq(&DriverType::Mssql) {
+            if let (Some(limit), None) = (limit, offset) {
+                return mssql_top_sql(sql, limit);
+            }
+            if trim_order_by(sql).eq(sql) {
+                return Ok(format!("{} ORDER BY (SELECT NULL){}", sql, self.limit_offset_sql(limit, offset)?));
+            }
+        }
+        Ok(format!("{}{}", sql, self.limit_offset_sql(limit, offset)?))
+    }
+}
+
+///insert 'TOP n' after the 'SELECT '(or 'SELECT DISTINCT ')
+fn mssql_top_sql(sql: &str, top: u64) -> rbatis_core::Result<String> {
+    let sql = sql.trim_start();
+    for prefix in &["SELECT DISTINCT ", "SELECT "] {
+        if sql.get(..prefix.len()).map(|x| x.eq_ignore_ascii_case(prefix)).unwrap_or(false) {
+            return Ok(format!("{}TOP {} {}", &sql[..prefix.len()], top, &sql[prefix.len()..]));
+        }
+    }
+    Err(rbatis_core::Error::from(format!("[rbatis] TOP need an SELECT sql,sql: {}", sql)))
 }
 
 #[test]
@@ -29,4 +121,29 @@ pub fn test_create_limit() {
     println!("{}", pg_limit);
     let sqlite_limit = DriverType::Sqlite.page_limit_sql(1, 20).unwrap();
     println!("{}", sqlite_limit);
+}
+
+#[test]
+pub fn test_limit_offset() {
+    assert_eq!(DriverType::Mysql.limit_offset_sql(Some(10), Some(20)).unwrap(), " LIMIT 10 OFFSET 20");
+    assert_eq!(DriverType::Mysql.limit_offset_sql(None, Some(20)).unwrap(), " LIMIT 18446744073709551615 OFFSET 20");
+    assert_eq!(DriverType::Sqlite.limit_offset_sql(None, Some(20)).unwrap(), " LIMIT -1 OFFSET 20");
+    assert_eq!(DriverType::Postgres.limit_offset_sql(None, Some(20)).unwrap(), " OFFSET 20");
+    assert_eq!(DriverType::Postgres.limit_offset_sql(None, None).unwrap(), "");
+    assert_eq!(DriverType::Mssql.limit_offset_sql(Some(10), Some(20)).unwrap(), " OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY");
+    assert_eq!(DriverType::Mssql.page_limit_sql(20, 10).unwrap(), " OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY");
+    assert_eq!(DriverType::Oracle.limit_offset_sql(Some(10), None).unwrap(), " FETCH NEXT 10 ROWS ONLY");
+    assert_eq!(DriverType::Oracle.limit_offset_sql(None, Some(20)).unwrap(), " OFFSET 20 ROWS");
+    assert_eq!(DriverType::ClickHouse.page_limit_sql(20, 10).unwrap(), " LIMIT 10 OFFSET 20");
+}
+
+#[test]
+pub fn test_limit_select_sql() {
+    let sql = "SELECT id,name FROM biz_activity";
+    assert_eq!(DriverType::Mssql.limit_select_sql(sql, Some(10), None).unwrap(), "SELECT TOP 10 id,name FROM biz_activity");
+    assert_eq!(DriverType::Mssql.limit_select_sql("select distinct name FROM biz_activity", Some(1), None).unwrap(), "select distinct TOP 1 name FROM biz_activity");
+    assert_eq!(DriverType::Mssql.limit_select_sql(sql, Some(10), Some(20)).unwrap(), "SELECT id,name FROM biz_activity ORDER BY (SELECT NULL) OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY");
+    assert_eq!(DriverType::Mssql.limit_select_sql("SELECT id FROM biz_activity ORDER BY id", None, Some(20)).unwrap(), "SELECT id FROM biz_activity ORDER BY id OFFSET 20 ROWS");
+    assert_eq!(DriverType::Mysql.limit_select_sql(sql, Some(10), None).unwrap(), "SELECT id,name FROM biz_activity LIMIT 10");
+    assert_eq!(DriverType::Mssql.limit_select_sql(sql, None, None).unwrap(), sql);
 }
\ No newline at end of file
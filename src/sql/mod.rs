@@ -4,14 +4,58 @@ pub mod limit;
 
 pub mod date;
 
+pub mod placeholder;
+
+pub mod aggregate;
+
+pub mod identifier;
+
+pub mod savepoint;
+
 
 pub trait PageLimit {
     /// return  sql
     fn page_limit_sql(&self, offset: u64, size: u64) -> rbatis_core::Result<String>;
+    /// return  sql of optional limit and offset,for example: " LIMIT 10 OFFSET 20"
+    fn limit_offset_sql(&self, limit: Option<u64>, offset: Option<u64>) -> rbatis_core::Result<String>;
+    /// return  the select sql with optional limit and offset,
+    /// Mssql use 'SELECT TOP n' or 'ORDER BY ... OFFSET n ROWS FETCH NEXT m ROWS ONLY'(ordered by '(SELECT NULL)' without ORDER BY)
+    fn limit_select_sql(&self, sql: &str, limit: Option<u64>, offset: Option<u64>) -> rbatis_core::Result<String>;
 }
 
 
 pub trait Date {
     /// return  (sql,value)
     fn date_convert(&self, value: &serde_json::Value, index: usize) -> rbatis_core::Result<(String, Value)>;
-}
\ No newline at end of file
+}
+
+
+pub trait Placeholder {
+    /// convert '?' placeholders(skip quoted string) of sql to driver placeholders,start from index.
+    /// for example on postgres: ("a = ? and b = ?", 2) -> "a = $3 and b = $4"
+    /// return  (sql,values)
+    fn convert_placeholder(&self, sql: &str, args: &[Value], start_index: usize) -> rbatis_core::Result<(String, Vec<Value>)>;
+}
+
+
+pub trait TimeBucket {
+    /// return  sql of an time bucket column,for example Mysql Day: "DATE_FORMAT(create_time, '%Y-%m-%d')"
+    fn time_bucket_sql(&self, column: &str, interval: &aggregate::Interval) -> rbatis_core::Result<String>;
+}
+
+
+
+pub trait QuoteIdentifier {
+    /// return  the quoted table/column name,for example Mysql: "`name`"
+    fn quote_identifier(&self, name: &str) -> String;
+}
+
+
+pub trait Savepoint {
+    /// return  sql of create an savepoint in tx,for example Mssql: "SAVE TRANSACTION sp1"
+    fn savepoint_sql(&self, name: &str) -> rbatis_core::Result<String>;
+    /// return  sql of rollback to an savepoint,for example Mssql: "ROLLBACK TRANSACTION sp1"
+    fn rollback_savepoint_sql(&self, name: &str) -> rbatis_core::Result<String>;
+    /// return  sql of release an savepoint,None if the driver have no release(Mssql,Oracle)
+    fn release_savepoint_sql(&self, name: &str) -> rbatis_core::Result<Option<String>>;
+}
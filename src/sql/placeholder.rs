@@ -0,0 +1,61 @@
+use serde_json::Value;
+
+use rbatis_core::convert::StmtConvert;
+use rbatis_core::db::DriverType;
+use rbatis_core::dialect::get_dialect;
+use rbatis_core::Error;
+
+use crate::sql::Placeholder;
+
+impl Placeholder for DriverType {
+    fn convert_placeholder(&self, sql: &str, args: &[Value], start_index: usize) -> rbatis_core::Result<(String, Vec<Value>)> {
+        if self.eq(&DriverType::None) && get_dialect(self).is_none() {
+            return Err(Error::from("[rbatis] convert_placeholder un support none for driver type!"));
+        }
+        let mut new_sql = String::with_capacity(sql.len() + args.len() * 4);
+        let mut index = start_index;
+        let mut quote: Option<char> = None;
+        for c in sql.chars() {
+            match quote {
+                Some(q) => {
+                    if c == q {
+                        quote = None;
+                    }
+                    new_sql.push(c);
+                }
+                None => {
+                    match c {
+                        '\'' | '"' | '`' => {
+                            quote = Some(c);
+                            new_sql.push(c);
+                        }
+                        '?' => {
+                            new_sql.push_str(self.stmt_convert(index).as_str());
+                            index += 1;
+                        }
+                        _ => {
+                            new_sql.push(c);
+                        }
+                    }
+                }
+            }
+        }
+        let placeholders = index - start_index;
+        if placeholders != args.len() {
+            return Err(Error::from(format!("[rbatis] convert_placeholder sql have {} placeholders,but args len = {}!", placeholders, args.len())));
+        }
+        return Ok((new_sql, args.to_vec()));
+    }
+}
+
+#[test]
+pub fn test_convert_placeholder() {
+    let (sql, args) = DriverType::Postgres.convert_placeholder("a = ? and b = '?' and c = ?", &[json!(1), json!(2)], 2).unwrap();
+    assert_eq!(sql, "a =  $3  and b = '?' and c =  $4 ");
+    assert_eq!(args, vec![json!(1), json!(2)]);
+
+    let (sql, _) = DriverType::Mysql.convert_placeholder("a = ?", &[json!(1)], 5).unwrap();
+    assert_eq!(sql, "a =  ? ");
+
+    assert!(DriverType::Mysql.convert_placeholder("a = ?", &[], 0).is_err());
+}
@@ -0,0 +1,59 @@
+use rbatis_core::db::DriverType;
+use rbatis_core::Error;
+
+use crate::sql::Savepoint;
+
+impl Savepoint for DriverType {
+    fn savepoint_sql(&self, name: &str) -> rbatis_core::Result<String> {
+        return match self {
+            DriverType::Mysql | DriverType::Postgres | DriverType::Sqlite | DriverType::Oracle => {
+                Ok(format!("SAVEPOINT {}", name))
+            }
+            DriverType::Mssql => {
+                Ok(format!("SAVE TRANSACTION {}", name))
+            }
+            DriverType::ClickHouse | DriverType::None => {
+                Err(Error::from(format!("[rbatis] not support savepoint for DriverType:{:?}", self)))
+            }
+        };
+    }
+
+    fn rollback_savepoint_sql(&self, name: &str) -> rbatis_core::Result<String> {
+        return match self {
+            DriverType::Mysql | DriverType::Postgres | DriverType::Sqlite | DriverType::Oracle => {
+                Ok(format!("ROLLBACK TO SAVEPOINT {}", name))
+            }
+            DriverType::Mssql => {
+                Ok(format!("ROLLBACK TRANSACTION {}", name))
+            }
+            DriverType::ClickHouse | DriverType::None => {
+                Err(Error::from(format!("[rbatis] not support savepoint for DriverType:{:?}", self)))
+            }
+        };
+    }
+
+    fn release_savepoint_sql(&self, name: &str) -> rbatis_core::Result<Option<String>> {
+        return match self {
+            DriverType::Mysql | DriverType::Postgres | DriverType::Sqlite => {
+                Ok(Some(format!("RELEASE SAVEPOINT {}", name)))
+            }
+            //the savepoint is released with the tx
+            DriverType::Mssql | DriverType::Oracle => {
+                Ok(None)
+            }
+            DriverType::ClickHouse | DriverType::None => {
+                Err(Error::from(format!("[rbatis] not support savepoint for DriverType:{:?}", self)))
+            }
+        };
+    }
+}
+
+#[test]
+pub fn test_savepoint_sql() {
+    assert_eq!(DriverType::Mysql.savepoint_sql("sp1").unwrap(), "SAVEPOINT sp1");
+    assert_eq!(DriverType::Mysql.release_savepoint_sql("sp1").unwrap(), Some("RELEASE SAVEPOINT sp1".to_string()));
+    assert_eq!(DriverType::Mssql.savepoint_sql("sp1").unwrap(), "SAVE TRANSACTION sp1");
+    assert_eq!(DriverType::Mssql.rollback_savepoint_sql("sp1").unwrap(), "ROLLBACK TRANSACTION sp1");
+    assert_eq!(DriverType::Mssql.release_savepoint_sql("sp1").unwrap(), None);
+    assert!(DriverType::ClickHouse.savepoint_sql("sp1").is_err());
+}
@@ -6,14 +6,16 @@ use serde_json::{json, Map, Value};
 
 use rbatis_core::convert::StmtConvert;
 use rbatis_core::db::DriverType;
+use rbatis_core::dialect::{get_dialect, shift_numbered_placeholders};
 use rbatis_core::Error;
 
 use crate::crud::CRUDEnable;
+use crate::sql::Placeholder;
 
 /// when sql not end with WHERE,AND,OR it will be append " AND "
 macro_rules! add_and {
         ($self:tt) => {
-          if !$self.sql.ends_with(" WHERE ") && !$self.sql.ends_with(" AND ") && !$self.sql.ends_with(" OR ") {
+          if !$self.sql.ends_with(" WHERE ") && !$self.sql.ends_with(" AND ") && !$self.sql.ends_with(" OR ") && !$self.sql.ends_with(" HAVING ") {
              $self.sql.push_str(" AND ");
           }
         };
@@ -21,12 +23,52 @@ macro_rules! add_and {
 
 macro_rules! add_or {
         ($self:tt) => {
-          if !$self.sql.ends_with(" WHERE ") && !$self.sql.ends_with(" AND ") && !$self.sql.ends_with(" OR ") {
+          if !$self.sql.ends_with(" WHERE ") && !$self.sql.ends_with(" AND ") && !$self.sql.ends_with(" OR ") && !$self.sql.ends_with(" HAVING ") {
               $self.sql.push_str(" OR ");
           }
         };
     }
 
+/// the Wrapper snapshot format version,increase it when the snapshot layout changed
+pub const WRAPPER_SNAPSHOT_VERSION: u32 = 2;
+
+/// an stable,canonical form of Wrapper(sql + args + the select parts),
+/// can be use as cache key,or persisted(for example saved filters) and replay later
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WrapperSnapshot {
+    pub version: u32,
+    pub driver_type: DriverType,
+    pub sql: String,
+    pub args: Vec<serde_json::Value>,
+    /// the select parts since version 2
+    #[serde(default)]
+    pub select: Option<String>,
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+    #[serde(default)]
+    pub joins: Vec<String>,
+    #[serde(default)]
+    pub join_ones: Vec<JoinOne>,
+    #[serde(default)]
+    pub distinct: bool,
+    #[serde(default)]
+    pub lock: Option<RowLock>,
+}
+
+/// many-to-one eager join of select,see Wrapper::join_one()
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct JoinOne {
+    /// the nested field of the main struct,also the alias of the joined table
+    pub field: String,
+    pub table: String,
+    /// the column of main table references the joined table id
+    pub foreign_key: String,
+    /// fields of the joined table
+    pub fields: Vec<String>,
+}
+
 /// you can serialize to JSON, and Clone, Debug
 /// use json rpc send this Wrapper to server
 ///
@@ -48,13 +90,32 @@ macro_rules! add_or {
 ///             .group_by(&["id"])
 ///             .order_by(true, &["id", "name"])
 ///             .check().unwrap();
-///
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Wrapper {
     pub driver_type: DriverType,
     pub sql: String,
     pub args: Vec<serde_json::Value>,
     pub error: Option<Error>,
+    /// select columns,None is select table_fields()
+    #[serde(default)]
+    pub select: Option<String>,
+    /// limit rows of select,the sql is made by driver type(PageLimit::limit_offset_sql)
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// join clauses of select,for example: "LEFT JOIN biz_order ON biz_order.activity_id = biz_activity.id"
+    #[serde(default)]
+    pub joins: Vec<String>,
+    /// many-to-one eager joins of select
+    #[serde(default)]
+    pub join_ones: Vec<JoinOne>,
+    /// SELECT DISTINCT
+    #[serde(default)]
+    pub distinct: bool,
+    /// row lock of select(must in an transaction)
+    #[serde(default)]
+    pub lock: Option<RowLock>,
 }
 
 impl Wrapper {
@@ -64,6 +125,13 @@ impl Wrapper {
             sql: "".to_string(),
             args: vec![],
             error: None,
+            select: None,
+            limit: None,
+            offset: None,
+            joins: vec![],
+            join_ones: vec![],
+            distinct: false,
+            lock: None,
         }
     }
 
@@ -73,9 +141,83 @@ impl Wrapper {
             sql: sql.to_string(),
             args: args.clone(),
             error: None,
+            select: None,
+            limit: None,
+            offset: None,
+            joins: vec![],
+            join_ones: vec![],
+            distinct: false,
+            lock: None,
         }
     }
 
+    /// query by example,every not null field of entity is an 'AND field = ?' condition
+    /// for example:
+    ///  let w = Wrapper::from_entity(&DriverType::Mysql, &BizActivity { name: Some("a".to_string()), status: Some(1), ..}).check()?;
+    ///  sql: name =  ?  AND status =  ?
+    pub fn from_entity<T>(driver_type: &DriverType, arg: &T) -> Self
+        where T: CRUDEnable {
+        let mut w = Wrapper::new(driver_type);
+        match T::make_field_value_map(driver_type, arg) {
+            Ok(map) => {
+                for (k, v) in map {
+                    if !v.is_null() {
+                        w.eq(k.as_str(), v);
+                    }
+                }
+            }
+            Err(e) => {
+                w.error = Some(e);
+            }
+        }
+        w
+    }
+
+    /// make an snapshot,the sql whitespace is normalized so equal queries have equal snapshot
+    pub fn to_snapshot(&self) -> WrapperSnapshot {
+        WrapperSnapshot {
+            version: WRAPPER_SNAPSHOT_VERSION,
+            driver_type: self.driver_type.clone(),
+            sql: normalize_sql(&self.sql),
+            args: self.args.clone(),
+            select: self.select.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            joins: self.joins.clone(),
+            join_ones: self.join_ones.clone(),
+            distinct: self.distinct,
+            lock: self.lock,
+        }
+    }
+
+    /// canonical json string of snapshot,can be use as cache key
+    pub fn to_snapshot_key(&self) -> String {
+        serde_json::to_string(&self.to_snapshot()).unwrap_or_default()
+    }
+
+    /// replay an Wrapper from snapshot
+    pub fn from_snapshot(arg: &WrapperSnapshot) -> Result<Self, Error> {
+        if arg.version > WRAPPER_SNAPSHOT_VERSION {
+            return Err(Error::from(format!("[rbatis] unsupported wrapper snapshot version:{},current version:{}", arg.version, WRAPPER_SNAPSHOT_VERSION)));
+        }
+        let mut w = Wrapper::from(&arg.driver_type, &arg.sql, &arg.args);
+        w.select = arg.select.clone();
+        w.limit = arg.limit;
+        w.offset = arg.offset;
+        w.joins = arg.joins.clone();
+        w.join_ones = arg.join_ones.clone();
+        w.distinct = arg.distinct;
+        w.lock = arg.lock;
+        Ok(w)
+    }
+
+    /// replay an Wrapper from snapshot key(json string)
+    pub fn from_snapshot_key(arg: &str) -> Result<Self, Error> {
+        let snapshot: WrapperSnapshot = serde_json::from_str(arg)
+            .map_err(|e| Error::from(format!("[rbatis] wrapper snapshot decode fail:{}", e)))?;
+        Wrapper::from_snapshot(&snapshot)
+    }
+
     //check is done？and return cloned Wrapper
     pub fn check(&mut self) -> Result<Wrapper, Error> {
         if self.error.is_some() {
@@ -89,6 +231,13 @@ impl Wrapper {
             sql: self.sql.clone(),
             args: self.args.clone(),
             error: self.error.clone(),
+            select: self.select.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            joins: self.joins.clone(),
+            join_ones: self.join_ones.clone(),
+            distinct: self.distinct,
+            lock: self.lock,
         };
         return Ok(clone);
     }
@@ -100,22 +249,16 @@ impl Wrapper {
     ///             .and()
     ///             .right_link_wrapper(&w)
     ///             .check().unwrap();
-    ///  println!("sql:{:?}", w2.sql.as_str());  // sql:"a =  $1 a =  $2 "
-    ///  println!("arg:{:?}", w2.args.clone()); // arg:[String("1"), String("2")]
+    ///  println!("sql:{:?}", w2.sql.as_str());  // sql:"b =  $1 AND a =  $2 "
+    ///  println!("arg:{:?}", w2.args.clone()); // arg:[String("2"), String("1")]
     ///
     pub fn right_link_wrapper(&mut self, arg: &Wrapper) -> &mut Self {
         self.right_link(&arg.driver_type, &arg.sql, &arg.args)
     }
 
     pub fn right_link(&mut self, driver_type: &DriverType, sql: &str, args: &Vec<Value>) -> &mut Self {
-        let mut new_sql = sql.to_string();
-        if driver_type.eq(&DriverType::Postgres) {
-            let arg_old_len = args.len();
-            for index in 0..arg_old_len {
-                let str = driver_type.stmt_convert(index);
-                new_sql = new_sql.replace(str.as_str(), driver_type.stmt_convert(index + arg_old_len).as_str());
-            }
-        }
+        //the placeholders of sql start from 1,shift them after the args of this Wrapper
+        let new_sql = shift_placeholders(driver_type, sql, self.args.len());
         self.sql.push_str(new_sql.as_str());
         for x in args {
             self.args.push(x.clone());
@@ -124,6 +267,57 @@ impl Wrapper {
     }
 
 
+    /// merge an other Wrapper conditions,both sides are wrapped in parentheses and the args re indexed.
+    /// only conditions can be merged(no ORDER BY/GROUP BY/HAVING),the joins of other is appended.
+    /// for example:
+    ///  let a = Wrapper::new(&DriverType::Postgres).eq("a", 1).or().eq("b", 2).check().unwrap();
+    ///  let b = Wrapper::new(&DriverType::Postgres).eq("c", 3).check().unwrap();
+    ///  a.merge(&b, Link::And)
+    ///  sql: (a =  $1  OR b =  $2) AND (c =  $3)
+    pub fn merge(&mut self, other: &Wrapper, link: Link) -> &mut Self {
+        if let Some(e) = &other.error {
+            self.error = Some(e.clone());
+            return self;
+        }
+        let trim = |sql: &str| -> String {
+            sql.trim()
+                .trim_start_matches("AND ").trim_start_matches("OR ")
+                .trim_end_matches(" AND").trim_end_matches(" OR")
+                .trim().to_string()
+        };
+        let self_sql = trim(&self.sql);
+        let other_sql = trim(&other.sql);
+        for x in [&self_sql, &other_sql].iter() {
+            if x.contains("ORDER BY ") || x.contains("GROUP BY ") || x.contains(" HAVING ") {
+                self.error = Some(Error::from("[rbatis] wrapper merge() only support conditions,can not have ORDER BY/GROUP BY/HAVING!"));
+                return self;
+            }
+        }
+        for x in &other.joins {
+            self.joins.push(x.clone());
+        }
+        for x in &other.join_ones {
+            self.join_ones.push(x.clone());
+        }
+        if other_sql.is_empty() {
+            return self;
+        }
+        let other_sql = shift_placeholders(&self.driver_type, &other_sql, self.args.len());
+        for x in &other.args {
+            self.args.push(x.clone());
+        }
+        if self_sql.is_empty() {
+            self.sql = format!("({})", other_sql);
+            return self;
+        }
+        let link = match link {
+            Link::And => "AND",
+            Link::Or => "OR",
+        };
+        self.sql = format!("({}) {} ({})", self_sql, link, other_sql);
+        self
+    }
+
     /// do method,if test is true
     /// for example:
     ///  let arg = 1;
@@ -168,6 +362,26 @@ impl Wrapper {
         self
     }
 
+    /// push raw sql with args,the '?' placeholders(out of quote) is converted to driver placeholders,
+    /// index continue after the exist args.
+    /// for example on postgres:
+    ///  w.eq("a", 1).and().push_sql_args("b = ? OR c = ?", &[json!(2), json!(3)])
+    ///  sql: a =  $1  AND b =  $2  OR c =  $3
+    pub fn push_sql_args(&mut self, sql: &str, args: &[Value]) -> &mut Self {
+        match self.driver_type.convert_placeholder(sql, args, self.args.len()) {
+            Ok((new_sql, new_args)) => {
+                self.sql.push_str(new_sql.as_str());
+                for x in new_args {
+                    self.args.push(x);
+                }
+            }
+            Err(e) => {
+                self.error = Some(e);
+            }
+        }
+        self
+    }
+
     pub fn trim_sql(&mut self, sql: &str) -> &mut Self {
         self.sql = self.sql.trim().to_string();
         self
@@ -187,20 +401,74 @@ impl Wrapper {
     /// link wrapper sql, if end with where , do nothing
     pub fn and(&mut self) -> &mut Self {
         add_and!(self);
-        self.sql.push_str(" AND ");
         self
     }
 
     /// link wrapper sql, if end with where , do nothing
     pub fn or(&mut self) -> &mut Self {
         add_or!(self);
-        self.sql.push_str(" OR ");
         self
     }
 
-    pub fn having(&mut self, sql_having: &str) -> &mut Self {
+    /// nested conditions in parentheses,link with AND
+    /// for example:
+    ///  w.eq("status", 1).and_group(|w| w.eq("a", 1).or().eq("b", 2))
+    ///  sql: status =  ?  AND (a =  ?  OR b =  ? )
+    pub fn and_group<F>(&mut self, method: F) -> &mut Self
+        where F: FnOnce(&mut Wrapper) -> &mut Wrapper {
         add_and!(self);
-        self.sql.push_str(format!(" HAVING {} ", sql_having).as_str());
+        self.push_group(method)
+    }
+
+    /// nested conditions in parentheses,link with OR
+    /// for example:
+    ///  w.eq("status", 1).or_group(|w| w.eq("a", 1).eq("b", 2))
+    ///  sql: status =  ?  OR (a =  ?  AND b =  ? )
+    pub fn or_group<F>(&mut self, method: F) -> &mut Self
+        where F: FnOnce(&mut Wrapper) -> &mut Wrapper {
+        add_or!(self);
+        self.push_group(method)
+    }
+
+    /// OR column = obj
+    pub fn or_eq<T>(&mut self, column: &str, obj: T) -> &mut Self
+        where T: Serialize {
+        self.or().eq(column, obj)
+    }
+
+    /// OR column IN (...),an empty obj match no row,so it is skipped(x OR FALSE is x)
+    pub fn or_in<T>(&mut self, column: &str, obj: &[T]) -> &mut Self
+        where T: Serialize {
+        if obj.is_empty() {
+            return self;
+        }
+        self.or().in_array(column, obj)
+    }
+
+    /// OR column LIKE obj
+    pub fn or_like<T>(&mut self, column: &str, obj: T) -> &mut Self
+        where T: Serialize {
+        self.or().like(column, obj)
+    }
+
+    fn push_group<F>(&mut self, method: F) -> &mut Self
+        where F: FnOnce(&mut Wrapper) -> &mut Wrapper {
+        //the inner wrapper carry the args,so the placeholder index continue
+        let mut inner = Wrapper::new(&self.driver_type);
+        inner.args = std::mem::replace(&mut self.args, vec![]);
+        method(&mut inner);
+        self.args = std::mem::replace(&mut inner.args, vec![]);
+        if let Some(e) = inner.error.take() {
+            self.error = Some(e);
+            return self;
+        }
+        inner.trim_and().trim_or();
+        let sql = inner.sql.trim();
+        if sql.is_empty() {
+            self.sql = self.sql.trim_end_matches(" AND ").trim_end_matches(" OR ").to_string();
+            return self;
+        }
+        self.sql.push_str(format!("({})", sql).as_str());
         self
     }
 
@@ -255,12 +523,96 @@ impl Wrapper {
         self
     }
 
+    /// select columns instead of table_fields(),for example: select(&["id", "name"])
+    pub fn select(&mut self, columns: &[&str]) -> &mut Self {
+        if columns.is_empty() {
+            self.error = Some(Error::from("[rbatis] wrapper select columns can not be empty!"));
+            return self;
+        }
+        self.select = Some(columns.join(","));
+        self
+    }
+
+    /// select distinct rows,for example: select(&["status"]).distinct() => 'SELECT DISTINCT status FROM ...'
+    pub fn distinct(&mut self) -> &mut Self {
+        self.distinct = true;
+        self
+    }
+
+    /// lock the selected rows,Mysql/Postgres/Oracle: FOR UPDATE,Mssql: WITH (UPDLOCK, ROWLOCK),Sqlite is not support(ignored)
+    pub fn for_update(&mut self) -> &mut Self {
+        self.lock = Some(RowLock::Update);
+        self
+    }
+
+    /// shared lock the selected rows,Mysql: LOCK IN SHARE MODE,Postgres: FOR SHARE,Mssql: WITH (HOLDLOCK, ROWLOCK),Oracle is Err,Sqlite is not support(ignored)
+    pub fn for_share(&mut self) -> &mut Self {
+        self.lock = Some(RowLock::Share);
+        self
+    }
+
+    /// inner join table on condition,the main table fields of select is prefixed with table name.
+    /// for example:
+    ///  w.inner_join("biz_order", "biz_order.activity_id = biz_activity.id").eq("biz_order.status", 1)
+    ///  sql: SELECT biz_activity.id,biz_activity.name... FROM biz_activity INNER JOIN biz_order ON biz_order.activity_id = biz_activity.id WHERE biz_order.status =  ?
+    pub fn inner_join(&mut self, table: &str, on: &str) -> &mut Self {
+        self.joins.push(format!("INNER JOIN {} ON {}", table, on));
+        self
+    }
+
+    /// left join table on condition,see inner_join()
+    pub fn left_join(&mut self, table: &str, on: &str) -> &mut Self {
+        self.joins.push(format!("LEFT JOIN {} ON {}", table, on));
+        self
+    }
+
+    /// many-to-one eager loading of list_by_wrapper/fetch_by_wrapper,LEFT JOIN the table of R(alias as field) on main_table.foreign_key = field.id,
+    /// the columns of R are selected as "field__column" and decoded into the nested field(None if not matched),so no N+1 queries.
+    /// the conditions should be prefixed with table name(same as inner_join).
+    /// for example:
+    ///  struct BizOrder { id: Option<String>, activity_id: Option<String>, activity: Option<BizActivity> }
+    ///  w.join_one::<BizActivity>("activity_id", "activity")
+    ///  sql: SELECT biz_order.id,biz_order.activity_id,activity.id AS activity__id,activity.name AS activity__name... FROM biz_order LEFT JOIN biz_activity activity ON biz_order.activity_id = activity.id
+    pub fn join_one<R>(&mut self, foreign_key: &str, field: &str) -> &mut Self where R: CRUDEnable {
+        let fields: Vec<String> = R::table_fields().split(",").map(|x| x.trim().to_string()).filter(|x| !x.is_empty()).collect();
+        if fields.is_empty() || fields.iter().any(|x| x == "*") {
+            self.error = Some(Error::from(format!("[rbatis] wrapper join_one() the fields of table {} is unknown,impl table_fields() of it!", R::table_name())));
+            return self;
+        }
+        self.join_ones.push(JoinOne {
+            field: field.to_string(),
+            table: R::table_name(),
+            foreign_key: foreign_key.to_string(),
+            fields,
+        });
+        self
+    }
+
+    /// have joins(or join_ones),the main table fields and conditions should be prefixed with table name
+    pub fn have_joins(&self) -> bool {
+        !self.joins.is_empty() || !self.join_ones.is_empty()
+    }
+
+    /// limit rows of select,for example: limit(10) => ' LIMIT 10'
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// skip rows of select,for example: limit(10).offset(20) => ' LIMIT 10 OFFSET 20'
+    pub fn offset(&mut self, offset: u64) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// order by columns,for example: order_by(false, &["create_time"]) => ' ORDER BY create_time DESC'
     pub fn order_by(&mut self, is_asc: bool, columns: &[&str]) -> &mut Self {
         let len = columns.len();
         if len == 0 {
             return self;
         }
         let mut index = 0;
+        self.sql = self.sql.trim_end_matches(" AND ").trim_end_matches(" OR ").to_string();
         self.sql = self.sql.trim_end_matches(" WHERE").trim_end_matches("WHERE ").to_string();
         self.sql.push_str(" ORDER BY ");
         for x in columns {
@@ -277,13 +629,21 @@ impl Wrapper {
         self
     }
 
+    /// group by columns,if the sql already have an ORDER BY,the GROUP BY is inserted before it.
+    /// for example: group_by(&["status"]).having("").gt("count(1)", 1).order_by(true, &["status"])
+    /// => ' GROUP BY status HAVING count(1) >  ?  ORDER BY status ASC'
     pub fn group_by(&mut self, columns: &[&str]) -> &mut Self {
         let len = columns.len();
         if len == 0 {
             return self;
         }
         let mut index = 0;
+        self.sql = self.sql.trim_end_matches(" AND ").trim_end_matches(" OR ").to_string();
         self.sql = self.sql.trim_end_matches(" WHERE").trim_end_matches("WHERE ").to_string();
+        let mut order_by = String::new();
+        if let Some(order_index) = self.sql.rfind(" ORDER BY ") {
+            order_by = self.sql.split_off(order_index);
+        }
         self.sql.push_str(" GROUP BY ");
         for x in columns {
             self.sql.push_str(x);
@@ -292,6 +652,23 @@ impl Wrapper {
                 index += 1;
             }
         }
+        self.sql.push_str(order_by.as_str());
+        self
+    }
+
+    /// having,must call after group_by. sql_having can be empty,then the conditions after it are the having conditions.
+    /// for example: group_by(&["status"]).having("count(1) > 1")
+    ///              group_by(&["status"]).having("").gt("count(1)", 1)
+    pub fn having(&mut self, sql_having: &str) -> &mut Self {
+        if !self.sql.contains(" GROUP BY ") {
+            self.error = Some(Error::from("[rbatis] wrapper having() must call after group_by()!"));
+            return self;
+        }
+        self.sql = self.sql.trim_end_matches(" AND ").trim_end_matches(" OR ").to_string();
+        self.sql.push_str(" HAVING ");
+        if !sql_having.is_empty() {
+            self.sql.push_str(format!("{} ", sql_having).as_str());
+        }
         self
     }
 
@@ -372,6 +749,28 @@ impl Wrapper {
         self.args.push(v);
         self
     }
+    /// case insensitive like,the arg is bind as '%obj%'.
+    /// Postgres: column ILIKE $1,Mysql/Sqlite: LOWER(column) LIKE LOWER(?)
+    pub fn ilike<T>(&mut self, column: &str, obj: T) -> &mut Self
+        where T: Serialize {
+        add_and!(self);
+        let v = match serde_json::to_value(obj).unwrap() {
+            Value::String(s) => s,
+            other => other.to_string(),
+        };
+        let placeholder = self.driver_type.stmt_convert(self.args.len());
+        match self.driver_type {
+            DriverType::Postgres => {
+                self.sql.push_str(format!("{} ILIKE {}", column, placeholder).as_str());
+            }
+            _ => {
+                self.sql.push_str(format!("LOWER({}) LIKE LOWER({})", column, placeholder).as_str());
+            }
+        }
+        self.args.push(json!(format!("%{}%", v)));
+        self
+    }
+
     pub fn like_left<T>(&mut self, column: &str, obj: T) -> &mut Self
         where T: Serialize {
         add_and!(self);
@@ -436,8 +835,76 @@ impl Wrapper {
         self
     }
 
+    /// in array,split an oversized IN list into OR groups in one statement,every group at most max_in_size items
+    /// for example(max_in_size = 2): (id IN ( ? , ? ) OR id IN ( ? ))
+    pub fn in_array_split<T>(&mut self, column: &str, obj: &[T], max_in_size: usize) -> &mut Self
+        where T: Serialize {
+        if max_in_size == 0 {
+            self.error = Some(Error::from("[rbatis] wrapper in_array_split max_in_size must > 0!"));
+            return self;
+        }
+        if obj.len() <= max_in_size {
+            return self.in_array(column, obj);
+        }
+        add_and!(self);
+        let v = serde_json::to_value(obj).unwrap();
+        let vec = v.as_array().unwrap();
+        let mut groups = vec![];
+        for chunk in vec.chunks(max_in_size) {
+            let mut sqls = String::new();
+            for x in chunk {
+                sqls.push_str(format!(" {} ", self.driver_type.stmt_convert(self.args.len())).as_str());
+                sqls.push_str(",");
+                self.args.push(x.clone());
+            }
+            sqls.pop();
+            groups.push(format!("{} IN ({})", column, sqls));
+        }
+        self.sql.push_str(format!("({})", groups.join(" OR ")).as_str());
+        self
+    }
+
+    /// exists sub query,the sub Wrapper args is merged(placeholder re indexed)
+    /// for example:
+    ///  let sub = Wrapper::new(&DriverType::Postgres).push_sql("o.user_id = u.id").and().eq("o.status", 1).check().unwrap();
+    ///  w.eq("u.status", 1).exists("biz_order o", &sub)
+    ///  sql: u.status =  $1  AND EXISTS (SELECT 1 FROM biz_order o WHERE o.user_id = u.id AND o.status =  $2 )
+    pub fn exists(&mut self, table: &str, sub: &Wrapper) -> &mut Self {
+        add_and!(self);
+        self.push_sub_query("EXISTS", table, sub)
+    }
+
+    /// not exists sub query,see exists()
+    pub fn not_exists(&mut self, table: &str, sub: &Wrapper) -> &mut Self {
+        add_and!(self);
+        self.push_sub_query("NOT EXISTS", table, sub)
+    }
+
+    fn push_sub_query(&mut self, op: &str, table: &str, sub: &Wrapper) -> &mut Self {
+        if let Some(e) = &sub.error {
+            self.error = Some(e.clone());
+            return self;
+        }
+        let sub_sql = sub.sql.trim().trim_start_matches("AND ").trim_start_matches("OR ");
+        let sub_sql = shift_placeholders(&self.driver_type, sub_sql, self.args.len());
+        if sub_sql.is_empty() {
+            self.sql.push_str(format!("{} (SELECT 1 FROM {})", op, table).as_str());
+        } else {
+            self.sql.push_str(format!("{} (SELECT 1 FROM {} WHERE {})", op, table, sub_sql).as_str());
+        }
+        for x in &sub.args {
+            self.args.push(x.clone());
+        }
+        self
+    }
+
+    /// empty obj is no-op(nothing excluded),not make an invalid 'NOT IN ()'
+    /// for example: not_in("id", &[1, 2]) => 'id NOT IN ( ? , ? )'
     pub fn not_in<T>(&mut self, column: &str, obj: &[T]) -> &mut Self
         where T: Serialize {
+        if obj.is_empty() {
+            return self;
+        }
         add_and!(self);
         let v = serde_json::to_value(obj).unwrap();
         self.sql.push_str(column);
@@ -453,6 +920,56 @@ impl Wrapper {
         self
     }
 
+    /// geo radius filter,the point(lat_column,lng_column) within meters of point(lat,lng)
+    /// Postgres use PostGIS(the database must 'CREATE EXTENSION postgis'): ST_DWithin(ST_MakePoint(lng,lat)::geography, ST_MakePoint(?,?)::geography, ?)
+    /// Mysql/Mssql use haversine formula,ClickHouse use greatCircleDistance(lng,lat,?,?) <= ?
+    /// Sqlite(the bundled one have no math functions) and Oracle(no RADIANS) are Err
+    pub fn within_radius(&mut self, lat_column: &str, lng_column: &str, lat: f64, lng: f64, meters: f64) -> &mut Self {
+        match self.driver_type {
+            DriverType::Mysql | DriverType::Postgres | DriverType::Mssql | DriverType::ClickHouse => {}
+            _ => {
+                self.error = Some(Error::from(format!("[rbatis] within_radius not support DriverType:{:?}", self.driver_type)));
+                return self;
+            }
+        }
+        add_and!(self);
+        match self.driver_type {
+            DriverType::Postgres => {
+                self.sql.push_str(format!("ST_DWithin(ST_MakePoint({}, {})::geography, ST_MakePoint({}, {})::geography, {})",
+                                          lng_column, lat_column,
+                                          self.driver_type.stmt_convert(self.args.len()),
+                                          self.driver_type.stmt_convert(self.args.len() + 1),
+                                          self.driver_type.stmt_convert(self.args.len() + 2)).as_str());
+                self.args.push(json!(lng));
+                self.args.push(json!(lat));
+                self.args.push(json!(meters));
+            }
+            DriverType::ClickHouse => {
+                self.sql.push_str(format!("greatCircleDistance({}, {}, {}, {}) <= {}",
+                                          lng_column, lat_column,
+                                          self.driver_type.stmt_convert(self.args.len()),
+                                          self.driver_type.stmt_convert(self.args.len() + 1),
+                                          self.driver_type.stmt_convert(self.args.len() + 2)).as_str());
+                self.args.push(json!(lng));
+                self.args.push(json!(lat));
+                self.args.push(json!(meters));
+            }
+            _ => {
+                //6371000 = earth radius(meters)
+                self.sql.push_str(format!("(6371000 * 2 * ASIN(SQRT(POWER(SIN(RADIANS({} - {}) / 2), 2) + COS(RADIANS({})) * COS(RADIANS({})) * POWER(SIN(RADIANS({} - {}) / 2), 2)))) <= {}",
+                                          lat_column, self.driver_type.stmt_convert(self.args.len()),
+                                          self.driver_type.stmt_convert(self.args.len() + 1), lat_column,
+                                          lng_column, self.driver_type.stmt_convert(self.args.len() + 2),
+                                          self.driver_type.stmt_convert(self.args.len() + 3)).as_str());
+                self.args.push(json!(lat));
+                self.args.push(json!(lat));
+                self.args.push(json!(lng));
+                self.args.push(json!(meters));
+            }
+        }
+        self
+    }
+
     pub fn trim_and(&mut self) -> &mut Self {
         self.sql = self.sql.trim_start_matches(" AND ").trim_end_matches(" AND ").to_string();
         self
@@ -464,6 +981,80 @@ impl Wrapper {
     }
 }
 
+/// shift the numbered placeholders(postgres '$1',mssql '@p1',oracle ':1',out of quote) by offset,other driver('?') is not changed.
+/// the registered dialect of the driver type shift them by Dialect::shift_placeholders()
+pub(crate) fn shift_placeholders(driver_type: &DriverType, sql: &str, offset: usize) -> String {
+    if let Some(dialect) = get_dialect(driver_type) {
+        return dialect.shift_placeholders(sql, offset);
+    }
+    let prefix = match driver_type {
+        DriverType::Postgres => "$",
+        DriverType::Mssql => "@p",
+        DriverType::Oracle => ":",
+        _ => return sql.to_string(),
+    };
+    shift_numbered_placeholders(sql, prefix, offset)
+}
+
+/// trim and collapse whitespace(out of quote) into one space
+fn normalize_sql(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut in_quote = false;
+    let mut last_space = false;
+    for c in sql.trim().chars() {
+        if c == '\'' {
+            in_quote = !in_quote;
+        }
+        if !in_quote && c.is_whitespace() {
+            if !last_space {
+                result.push(' ');
+            }
+            last_space = true;
+            continue;
+        }
+        last_space = false;
+        result.push(c);
+    }
+    result
+}
+
+/// row lock of select
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RowLock {
+    Update,
+    Share,
+}
+
+impl RowLock {
+    /// lock sql of driver,for example: " FOR UPDATE",Err if the driver can not lock the rows in this mode(Oracle has no shared row lock)
+    pub fn sql(&self, driver_type: &DriverType) -> Result<String, Error> {
+        match (driver_type, self) {
+            (DriverType::Mysql, RowLock::Update) | (DriverType::Postgres, RowLock::Update) | (DriverType::Oracle, RowLock::Update) => Ok(" FOR UPDATE".to_string()),
+            (DriverType::Mysql, RowLock::Share) => Ok(" LOCK IN SHARE MODE".to_string()),
+            (DriverType::Postgres, RowLock::Share) => Ok(" FOR SHARE".to_string()),
+            (DriverType::Oracle, RowLock::Share) => Err(Error::from("[rbatis] Oracle not support shared row lock,use for_update()!")),
+            //sqlite lock the whole database in transaction,mssql use the table_hint()
+            _ => Ok(String::new()),
+        }
+    }
+
+    /// lock hint after the table name of driver,for example Mssql: " WITH (UPDLOCK, ROWLOCK)"
+    pub fn table_hint(&self, driver_type: &DriverType) -> String {
+        match (driver_type, self) {
+            (DriverType::Mssql, RowLock::Update) => " WITH (UPDLOCK, ROWLOCK)".to_string(),
+            (DriverType::Mssql, RowLock::Share) => " WITH (HOLDLOCK, ROWLOCK)".to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// link of Wrapper::merge
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Link {
+    And,
+    Or,
+}
+
 pub struct Case {
     test: bool,
     func: Box<dyn Fn(&mut Wrapper) -> &mut Wrapper>,
@@ -485,13 +1076,25 @@ impl Case {
 
 
 mod test {
+    use std::sync::Arc;
+
     use serde_json::json;
     use serde_json::Map;
 
     use rbatis_core::db::DriverType;
+    use rbatis_core::dialect::{Dialect, remove_dialect, set_dialect, shift_numbered_placeholders};
 
     use crate::utils::bencher::Bencher;
-    use crate::wrapper::{Case, Wrapper};
+    use crate::crud::CRUDEnable;
+    use crate::wrapper::{Case, Link, RowLock, Wrapper, WRAPPER_SNAPSHOT_VERSION};
+
+    #[test]
+    fn test_order_by() {
+        let w = Wrapper::new(&DriverType::Mysql).eq("id", 1).and().order_by(false, &["create_time", "id"]).check().unwrap();
+        assert_eq!(w.sql, "id =  ?  ORDER BY create_time DESC , id DESC");
+        let w = Wrapper::new(&DriverType::Mysql).order_by(true, &["id"]).check().unwrap();
+        assert_eq!(w.sql, " ORDER BY id ASC");
+    }
 
     #[test]
     fn test_select() {
@@ -555,6 +1158,295 @@ mod test {
         assert_eq!(ms.len(), w.args.len());
     }
 
+    #[test]
+    fn test_link_update_by_id_mssql() {
+        //'UPDATE ... SET' of update_by_id carry 2 args,the linked id Wrapper start from @p1
+        let mut w = Wrapper::new(&DriverType::Mssql);
+        w.sql = "UPDATE biz_activity SET name =  @p1 , version =  @p2  WHERE ".to_string();
+        w.args = vec![json!("a"), json!(1)];
+        let id_w = Wrapper::new(&DriverType::Mssql).eq("id", "1").and().eq("delete_flag", 1).check().unwrap();
+        let w = w.right_link_wrapper(&id_w).check().unwrap();
+        assert_eq!(w.sql, "UPDATE biz_activity SET name =  @p1 , version =  @p2  WHERE id =  @p3  AND delete_flag =  @p4 ");
+        assert_eq!(w.args, vec![json!("a"), json!(1), json!("1"), json!(1)]);
+    }
+
+    #[test]
+    fn test_link_update_by_id_oracle() {
+        let mut w = Wrapper::new(&DriverType::Oracle);
+        w.sql = "UPDATE biz_activity SET name =  :1 , version =  :2  WHERE ".to_string();
+        w.args = vec![json!("a"), json!(1)];
+        let id_w = Wrapper::new(&DriverType::Oracle).eq("id", "1").and().eq("delete_flag", 1).check().unwrap();
+        let w = w.right_link_wrapper(&id_w).check().unwrap();
+        assert_eq!(w.sql, "UPDATE biz_activity SET name =  :1 , version =  :2  WHERE id =  :3  AND delete_flag =  :4 ");
+        assert_eq!(w.args, vec![json!("a"), json!(1), json!("1"), json!(1)]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("a", "1").and().in_array("b", &[1, 2]).check().unwrap();
+        let key = w.to_snapshot_key();
+        println!("key:{}", key);
+        let w2 = Wrapper::from_snapshot_key(key.as_str()).unwrap();
+        assert_eq!(w.to_snapshot(), w2.to_snapshot());
+        assert_eq!(key, w2.to_snapshot_key());
+        assert_eq!(w2.args, w.args);
+
+        let mut snapshot = w.to_snapshot();
+        snapshot.version = WRAPPER_SNAPSHOT_VERSION + 1;
+        assert!(Wrapper::from_snapshot(&snapshot).is_err());
+
+        //the select parts are kept
+        let w = Wrapper::new(&DriverType::Mysql).select(&["id", "name"]).distinct()
+            .left_join("biz_order", "biz_order.activity_id = biz_activity.id")
+            .eq("status", 1).limit(10).offset(20).for_update()
+            .check().unwrap();
+        let w2 = Wrapper::from_snapshot_key(&w.to_snapshot_key()).unwrap();
+        assert_eq!(w2.sql, w.sql);
+        assert_eq!(w2.args, w.args);
+        assert_eq!(w2.select, w.select);
+        assert_eq!((w2.limit, w2.offset), (Some(10), Some(20)));
+        assert_eq!(w2.joins, w.joins);
+        assert!(w2.distinct);
+        assert_eq!(w2.lock, Some(RowLock::Update));
+        assert_eq!(w2.to_snapshot(), w.to_snapshot());
+
+        //version 1 snapshot(sql + args) still replay
+        let w1 = Wrapper::from_snapshot_key(r#"{"version":1,"driver_type":"Mysql","sql":"id = ?","args":[1]}"#).unwrap();
+        assert_eq!(w1.sql, "id = ?");
+        assert_eq!(w1.limit, None);
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let w = Wrapper::new(&DriverType::Mysql).eq("status", 1).within_radius("lat", "lng", 31.2, 121.5, 1000.0).check().unwrap();
+        println!("sql:{:?}", w.sql.as_str());
+        let ms: Vec<&str> = w.sql.matches("?").collect();
+        assert_eq!(ms.len(), w.args.len());
+
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).within_radius("lat", "lng", 31.2, 121.5, 1000.0).check().unwrap();
+        println!("sql:{:?}", w.sql.as_str());
+        assert!(w.sql.contains("ST_DWithin"));
+        assert!(w.sql.contains("$4"));
+        assert_eq!(w.args.len(), 4);
+
+        let w = Wrapper::new(&DriverType::ClickHouse).within_radius("lat", "lng", 31.2, 121.5, 1000.0).check().unwrap();
+        assert_eq!(w.sql, "greatCircleDistance(lng, lat,  ? ,  ? ) <=  ? ");
+        assert!(Wrapper::new(&DriverType::Sqlite).within_radius("lat", "lng", 31.2, 121.5, 1000.0).check().is_err());
+        assert!(Wrapper::new(&DriverType::Oracle).within_radius("lat", "lng", 31.2, 121.5, 1000.0).check().is_err());
+    }
+
+    #[test]
+    fn test_group_by_having() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("delete_flag", 1)
+            .order_by(true, &["status"])
+            .group_by(&["status"])
+            .check().unwrap();
+        assert_eq!(w.sql, "delete_flag =  $1  GROUP BY status ORDER BY status ASC");
+        let w = Wrapper::new(&DriverType::Postgres).eq("delete_flag", 1)
+            .group_by(&["status"])
+            .having("").gt("count(1)", 1)
+            .order_by(true, &["status"])
+            .check().unwrap();
+        assert_eq!(w.sql, "delete_flag =  $1  GROUP BY status HAVING count(1) >  $2  ORDER BY status ASC");
+        let w = Wrapper::new(&DriverType::Mysql).group_by(&["status"]).having("count(1) > 1").check().unwrap();
+        assert_eq!(w.sql, " GROUP BY status HAVING count(1) > 1 ");
+        assert!(Wrapper::new(&DriverType::Postgres).having("count(1) > 1").check().is_err());
+    }
+
+    #[test]
+    fn test_and_group() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1)
+            .and_group(|w| w.eq("a", 1).or().eq("b", 2))
+            .and_group(|w| w.eq("c", 3).eq("d", 4))
+            .check().unwrap();
+        assert_eq!(w.sql, "status =  $1  AND (a =  $2  OR b =  $3) AND (c =  $4  AND d =  $5)");
+        assert_eq!(w.args.len(), 5);
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).and_group(|w| w).check().unwrap();
+        assert_eq!(w.sql, "status =  $1 ");
+    }
+
+    #[test]
+    fn test_exists() {
+        let sub = Wrapper::new(&DriverType::Postgres).push_sql("o.user_id = u.id").and().eq("o.status", 2).check().unwrap();
+        let w = Wrapper::new(&DriverType::Postgres).eq("u.status", 1)
+            .exists("biz_order o", &sub)
+            .not_exists("biz_ban b", &Wrapper::new(&DriverType::Postgres).push_sql("b.user_id = u.id").check().unwrap())
+            .check().unwrap();
+        assert_eq!(w.sql, "u.status =  $1  AND EXISTS (SELECT 1 FROM biz_order o WHERE o.user_id = u.id AND o.status =  $2) AND NOT EXISTS (SELECT 1 FROM biz_ban b WHERE b.user_id = u.id)");
+        assert_eq!(w.args, vec![json!(1), json!(2)]);
+    }
+
+    #[test]
+    fn test_push_sql_args() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("a", 1).and()
+            .push_sql_args("b = ? OR c = '?'", &[json!(2)])
+            .check().unwrap();
+        assert_eq!(w.sql, "a =  $1  AND b =  $2  OR c = '?'");
+        assert_eq!(w.args, vec![json!(1), json!(2)]);
+        assert!(Wrapper::new(&DriverType::Postgres).push_sql_args("b = ?", &[]).check().is_err());
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+    pub struct BizActivity {
+        pub id: Option<String>,
+        pub name: Option<String>,
+        pub status: Option<i32>,
+    }
+
+    impl CRUDEnable for BizActivity {
+        type IdType = String;
+    }
+
+    #[test]
+    fn test_from_entity() {
+        let arg = BizActivity { id: None, name: Some("a".to_string()), status: Some(1) };
+        let w = Wrapper::from_entity(&DriverType::Postgres, &arg).check().unwrap();
+        assert_eq!(w.sql, "name =  $1  AND status =  $2 ");
+        assert_eq!(w.args, vec![json!("a"), json!(1)]);
+    }
+
+    #[test]
+    fn test_join() {
+        let w = Wrapper::new(&DriverType::Mysql)
+            .inner_join("biz_order", "biz_order.activity_id = biz_activity.id")
+            .left_join("biz_user u", "u.id = biz_order.user_id")
+            .eq("biz_order.status", 1)
+            .check().unwrap();
+        assert_eq!(w.joins, vec!["INNER JOIN biz_order ON biz_order.activity_id = biz_activity.id".to_string(),
+                                 "LEFT JOIN biz_user u ON u.id = biz_order.user_id".to_string()]);
+        assert_eq!(w.sql, "biz_order.status =  ? ");
+    }
+
+    #[test]
+    fn test_row_lock() {
+        let w = Wrapper::new(&DriverType::Mysql).eq("id", 1).for_update().check().unwrap();
+        assert_eq!(w.lock, Some(RowLock::Update));
+        assert_eq!(RowLock::Update.sql(&DriverType::Postgres).unwrap(), " FOR UPDATE");
+        assert_eq!(RowLock::Share.sql(&DriverType::Mysql).unwrap(), " LOCK IN SHARE MODE");
+        assert_eq!(RowLock::Share.sql(&DriverType::Sqlite).unwrap(), "");
+        assert_eq!(RowLock::Update.sql(&DriverType::Mssql).unwrap(), "");
+        assert_eq!(RowLock::Update.sql(&DriverType::Oracle).unwrap(), " FOR UPDATE");
+        assert!(RowLock::Share.sql(&DriverType::Oracle).is_err());
+        assert_eq!(RowLock::Update.table_hint(&DriverType::Mssql), " WITH (UPDLOCK, ROWLOCK)");
+        assert_eq!(RowLock::Share.table_hint(&DriverType::Mssql), " WITH (HOLDLOCK, ROWLOCK)");
+        assert_eq!(RowLock::Update.table_hint(&DriverType::Mysql), "");
+    }
+
+    #[test]
+    fn test_not_in_empty() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).not_in("id", &[1, 2]).check().unwrap();
+        assert_eq!(w.sql, "status =  $1  AND id NOT IN ( $2 , $3 )");
+        let empty: Vec<i32> = vec![];
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).not_in("id", &empty).eq("name", "a").check().unwrap();
+        assert_eq!(w.sql, "status =  $1  AND name =  $2 ");
+    }
+
+    #[test]
+    fn test_distinct() {
+        let w = Wrapper::new(&DriverType::Mysql).select(&["status"]).distinct().check().unwrap();
+        assert!(w.distinct);
+        assert!(!Wrapper::new(&DriverType::Mysql).check().unwrap().distinct);
+    }
+
+    #[test]
+    fn test_ilike() {
+        let w = Wrapper::new(&DriverType::Postgres).ilike("name", "Ab").check().unwrap();
+        assert_eq!(w.sql, "name ILIKE  $1 ");
+        assert_eq!(w.args, vec![json!("%Ab%")]);
+        let w = Wrapper::new(&DriverType::Mysql).ilike("name", 1).check().unwrap();
+        assert_eq!(w.sql, "LOWER(name) LIKE LOWER( ? )");
+        assert_eq!(w.args, vec![json!("%1%")]);
+    }
+
+    #[test]
+    fn test_or() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1)
+            .or_eq("status", 2)
+            .or_in("id", &[1, 2])
+            .or_group(|w| w.eq("a", 1).eq("b", 2))
+            .check().unwrap();
+        assert_eq!(w.sql, "status =  $1  OR status =  $2  OR id IN ( $3 , $4 ) OR (a =  $5  AND b =  $6)");
+        let w = Wrapper::new(&DriverType::Postgres).or_eq("status", 1).check().unwrap();
+        assert_eq!(w.sql, "status =  $1 ");
+        //empty or_in is skipped,the next condition is still AND
+        let empty: [i32; 0] = [];
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).or_in("id", &empty).eq("a", 2).check().unwrap();
+        assert_eq!(w.sql, "status =  $1  AND a =  $2 ");
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).or_in("id", &empty).check().unwrap();
+        assert_eq!(w.sql, "status =  $1 ");
+    }
+
+    #[test]
+    fn test_merge() {
+        let b = Wrapper::new(&DriverType::Postgres).eq("c", 3).check().unwrap();
+        let w = Wrapper::new(&DriverType::Postgres).eq("a", 1).or().eq("b", 2)
+            .merge(&b, Link::And)
+            .check().unwrap();
+        assert_eq!(w.sql, "(a =  $1  OR b =  $2) AND (c =  $3)");
+        assert_eq!(w.args, vec![json!(1), json!(2), json!(3)]);
+        let w = Wrapper::new(&DriverType::Postgres).merge(&b, Link::Or).eq("d", 4).check().unwrap();
+        assert_eq!(w.sql, "(c =  $1) AND d =  $2 ");
+        let order = Wrapper::new(&DriverType::Postgres).order_by(true, &["id"]).check().unwrap();
+        assert!(Wrapper::new(&DriverType::Postgres).merge(&order, Link::And).check().is_err());
+
+        let b = Wrapper::new(&DriverType::Mssql).eq("c", 3).check().unwrap();
+        let w = Wrapper::new(&DriverType::Mssql).eq("a", 1).or().eq("b", 2)
+            .merge(&b, Link::And)
+            .check().unwrap();
+        assert_eq!(w.sql, "(a =  @p1  OR b =  @p2) AND (c =  @p3)");
+    }
+
+    struct DollarDialect {}
+
+    impl Dialect for DollarDialect {
+        fn stmt_convert(&self, index: usize) -> String {
+            format!(" ${} ", index + 1)
+        }
+
+        fn shift_placeholders(&self, sql: &str, offset: usize) -> String {
+            shift_numbered_placeholders(sql, "$", offset)
+        }
+    }
+
+    #[test]
+    fn test_merge_dialect() {
+        set_dialect(DriverType::None, Arc::new(DollarDialect {}));
+        let b = Wrapper::new(&DriverType::None).eq("c", 3).check().unwrap();
+        let w = Wrapper::new(&DriverType::None).eq("a", 1).or().eq("b", 2)
+            .merge(&b, Link::And)
+            .check();
+        remove_dialect(&DriverType::None);
+        assert_eq!(w.unwrap().sql, "(a =  $1  OR b =  $2) AND (c =  $3)");
+    }
+
+    #[test]
+    fn test_limit_offset() {
+        let w = Wrapper::new(&DriverType::Mysql).eq("id", 1).limit(10).offset(20).check().unwrap();
+        assert_eq!(w.limit, Some(10));
+        assert_eq!(w.offset, Some(20));
+        assert_eq!(w.sql, "id =  ? ");
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let w = Wrapper::new(&DriverType::Mysql).select(&["id", "name"]).eq("id", 1).check().unwrap();
+        assert_eq!(w.select, Some("id,name".to_string()));
+        assert!(Wrapper::new(&DriverType::Mysql).select(&[]).check().is_err());
+    }
+
+    #[test]
+    fn test_in_array_split() {
+        let w = Wrapper::new(&DriverType::Postgres).eq("status", 1).in_array_split("id", &[1, 2, 3, 4, 5], 2).check().unwrap();
+        println!("sql:{:?}", w.sql.as_str());
+        assert_eq!(w.sql.matches(" IN ").count(), 3);
+        assert_eq!(w.sql.matches(" OR ").count(), 2);
+        assert!(w.sql.contains("$6"));
+        assert_eq!(w.args.len(), 6);
+
+        let w = Wrapper::new(&DriverType::Mysql).in_array_split("id", &[1, 2], 2).check().unwrap();
+        assert!(!w.sql.contains(" OR "));
+    }
+
     #[test]
     fn test_do_is_some() {
         let p = Option::<i32>::Some(1);